@@ -1,13 +1,42 @@
+use tauri::AppHandle;
+
+use crate::commands::timer::emit_achievements_unlocked;
 use crate::error::AppError;
 use crate::storage::achievements::{
-    get_achievements_with_status, get_total_session_count, AchievementWithStatus,
+    evaluate_achievements_from_history, get_achievements_with_status, get_closest_achievements,
+    get_total_session_count, AchievementWithStatus,
 };
+use crate::storage::progression::{get_progression as load_progression, Progression};
+
+/// Number of achievements returned by `get_next_achievements` by default
+const DEFAULT_NEXT_ACHIEVEMENTS: usize = 3;
 
 #[tauri::command]
 pub fn get_achievements() -> Result<Vec<AchievementWithStatus>, AppError> {
     get_achievements_with_status()
 }
 
+/// Locked achievements closest to being unlocked
+#[tauri::command]
+pub fn get_next_achievements(limit: Option<usize>) -> Result<Vec<AchievementWithStatus>, AppError> {
+    get_closest_achievements(limit.unwrap_or(DEFAULT_NEXT_ACHIEVEMENTS))
+}
+
+/// Unlock achievements earned in past sessions. Returns how many were unlocked.
+#[tauri::command]
+pub fn evaluate_achievements(app: AppHandle) -> Result<u32, AppError> {
+    let newly_unlocked = evaluate_achievements_from_history()?;
+    let count = newly_unlocked.len() as u32;
+    emit_achievements_unlocked(&app, newly_unlocked);
+    Ok(count)
+}
+
+/// Current XP and level
+#[tauri::command]
+pub fn get_progression() -> Result<Progression, AppError> {
+    load_progression()
+}
+
 #[tauri::command]
 pub fn get_total_sessions() -> Result<u32, AppError> {
     get_total_session_count()
@@ -0,0 +1,81 @@
+use chrono::Local;
+use tauri::AppHandle;
+use tauri_plugin_dialog::DialogExt;
+
+use crate::commands::timer::{emit_achievements_unlocked, emit_streak_updated};
+use crate::storage::achievements::{evaluate_achievements_from_history, load_achievements};
+use crate::storage::backup::{
+    backup_file_name, create_backup_archive, read_backup_archive, restore_backup_archive,
+    BackupManifest, BackupResult, RestoreMode, RestoreReport,
+};
+
+/// Ask for a destination and write a full backup archive there.
+/// The integrity key is only included when `include_integrity_key` is set.
+/// Returns `None` when the save dialog is cancelled.
+#[tauri::command]
+pub async fn create_backup(
+    app: AppHandle,
+    include_integrity_key: Option<bool>,
+) -> Result<Option<BackupResult>, String> {
+    let file = app
+        .dialog()
+        .file()
+        .set_title("Save Backup")
+        .set_file_name(backup_file_name(Local::now()))
+        .add_filter("Backup archive", &["gz"])
+        .blocking_save_file();
+
+    let Some(file) = file else {
+        return Ok(None);
+    };
+
+    let path = file.into_path().map_err(|e| e.to_string())?;
+    let manifest = create_backup_archive(&path, include_integrity_key.unwrap_or(false))
+        .map_err(|e| e.to_string())?;
+
+    Ok(Some(BackupResult {
+        path: path.to_string_lossy().to_string(),
+        manifest,
+    }))
+}
+
+#[tauri::command]
+pub async fn pick_backup_file(app: AppHandle) -> Result<Option<String>, String> {
+    let file = app
+        .dialog()
+        .file()
+        .set_title("Select Backup to Restore")
+        .add_filter("Backup archive", &["gz"])
+        .blocking_pick_file();
+
+    Ok(file.map(|p| p.to_string()))
+}
+
+/// Validate a backup and return its manifest so the user can choose merge or replace
+#[tauri::command]
+pub async fn inspect_backup(path: String) -> Result<BackupManifest, String> {
+    read_backup_archive(std::path::Path::new(&path))
+        .map(|contents| contents.manifest)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn restore_backup(
+    app: AppHandle,
+    path: String,
+    mode: RestoreMode,
+) -> Result<RestoreReport, String> {
+    let report = restore_backup_archive(std::path::Path::new(&path), mode)
+        .map_err(|e| e.to_string())?;
+
+    if let Ok(achievements) = load_achievements() {
+        emit_streak_updated(&app, &achievements);
+    }
+
+    match evaluate_achievements_from_history() {
+        Ok(newly_unlocked) => emit_achievements_unlocked(&app, newly_unlocked),
+        Err(e) => eprintln!("Failed to evaluate achievements after restore: {}", e),
+    }
+
+    Ok(report)
+}
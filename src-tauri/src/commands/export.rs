@@ -0,0 +1,35 @@
+use tauri::AppHandle;
+use tauri_plugin_dialog::DialogExt;
+
+use crate::storage::export::{
+    default_export_file_name, export_sessions_to_path, ExportFormat, ExportResult,
+};
+use crate::storage::sessions::DateRange;
+
+/// Ask the user for a destination file and export the sessions in `range` to it.
+/// Returns `None` when the save dialog is cancelled.
+#[tauri::command]
+pub async fn export_sessions(
+    app: AppHandle,
+    range: DateRange,
+    format: ExportFormat,
+) -> Result<Option<ExportResult>, String> {
+    range.validate().map_err(|e| e.to_string())?;
+
+    let file = app
+        .dialog()
+        .file()
+        .set_title("Export Sessions")
+        .set_file_name(default_export_file_name(&range, format))
+        .add_filter(format.label(), &[format.extension()])
+        .blocking_save_file();
+
+    let Some(file) = file else {
+        return Ok(None);
+    };
+
+    let path = file.into_path().map_err(|e| e.to_string())?;
+    export_sessions_to_path(&range, format, &path)
+        .map(Some)
+        .map_err(|e| e.to_string())
+}
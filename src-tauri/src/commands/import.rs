@@ -0,0 +1,48 @@
+use tauri::AppHandle;
+use tauri_plugin_dialog::DialogExt;
+
+use crate::commands::timer::{emit_achievements_unlocked, emit_streak_updated};
+use crate::storage::achievements::{evaluate_achievements_from_history, recalculate_after_import};
+use crate::storage::import::{import_sessions_from_path, ImportReport, ImportSource};
+use crate::storage::settings::load_settings;
+
+#[tauri::command]
+pub async fn pick_import_file(app: AppHandle) -> Result<Option<String>, String> {
+    let file = app
+        .dialog()
+        .file()
+        .set_title("Select File to Import")
+        .add_filter("Session exports", &["csv", "json"])
+        .blocking_pick_file();
+
+    Ok(file.map(|p| p.to_string()))
+}
+
+/// Import sessions from another tool. With `dry_run` the file is only parsed and
+/// de-duplicated so the frontend can show a preview.
+#[tauri::command]
+pub async fn import_sessions(
+    app: AppHandle,
+    path: String,
+    source: ImportSource,
+    dry_run: bool,
+) -> Result<ImportReport, String> {
+    let report = import_sessions_from_path(std::path::Path::new(&path), &source, dry_run)
+        .map_err(|e| e.to_string())?;
+
+    if !dry_run && !report.new_sessions.is_empty() {
+        // Imported sessions are never verified
+        let counted = if load_settings().map_err(|e| e.to_string())?.count_only_verified_sessions {
+            0
+        } else {
+            report.imported_complete_focus
+        };
+        let achievements = recalculate_after_import(counted).map_err(|e| e.to_string())?;
+        emit_streak_updated(&app, &achievements);
+
+        let newly_unlocked = evaluate_achievements_from_history().map_err(|e| e.to_string())?;
+        emit_achievements_unlocked(&app, newly_unlocked);
+    }
+
+    Ok(report)
+}
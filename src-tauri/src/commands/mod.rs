@@ -1,5 +1,8 @@
 pub mod achievements;
 pub mod app;
+pub mod backup;
+pub mod export;
+pub mod import;
 pub mod session;
 pub mod settings;
 pub mod stats;
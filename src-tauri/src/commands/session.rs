@@ -1,7 +1,13 @@
 use chrono::{Local, NaiveDate};
-use tauri::State;
+use tauri::{AppHandle, State};
 
+use crate::commands::timer::{emit_achievements_unlocked, emit_streak_updated};
 use crate::state::TimerStateWrapper;
+use crate::storage::achievements::evaluate_achievements_from_history;
+use crate::storage::conflicts::{
+    recalculate_after_merge, resolve_sync_conflicts, ConflictMergeReport,
+};
+use crate::storage::integrity::{verify_history as verify_session_history, HistoryVerificationReport};
 use crate::storage::sessions::{
     load_sessions_for_date, load_today_sessions, save_session, DailySummary, Session,
     SessionStatus, SessionType,
@@ -124,3 +130,25 @@ pub fn save_interrupted_session(
         summary: daily_file.summary,
     })
 }
+
+/// Merge sync-conflict copies of day files into the canonical files
+#[tauri::command]
+pub fn resolve_sync_conflicts_cmd(app: AppHandle) -> Result<ConflictMergeReport, String> {
+    let report = resolve_sync_conflicts().map_err(|e| e.to_string())?;
+
+    if report.sessions_added > 0 {
+        let achievements = recalculate_after_merge(&report).map_err(|e| e.to_string())?;
+        emit_streak_updated(&app, &achievements);
+
+        let newly_unlocked = evaluate_achievements_from_history().map_err(|e| e.to_string())?;
+        emit_achievements_unlocked(&app, newly_unlocked);
+    }
+
+    Ok(report)
+}
+
+/// Check the integrity chain of every session file and report edited or injected entries
+#[tauri::command]
+pub fn verify_history() -> Result<HistoryVerificationReport, String> {
+    verify_session_history().map_err(|e| e.to_string())
+}
@@ -1,6 +1,8 @@
 use tauri::AppHandle;
 use tauri_plugin_dialog::DialogExt;
 
+use crate::commands::timer::emit_streak_updated;
+use crate::storage::achievements::recalculate_streak_for_rules;
 use crate::storage::settings::{
     get_data_directory, get_effective_sessions_directory, load_settings, migrate_storage,
     save_settings, validate_storage_path, Settings,
@@ -12,8 +14,21 @@ pub async fn get_settings() -> Result<Settings, String> {
 }
 
 #[tauri::command]
-pub async fn update_settings(settings: Settings) -> Result<(), String> {
-    save_settings(&settings).map_err(|e| e.to_string())
+pub async fn update_settings(app: AppHandle, settings: Settings) -> Result<(), String> {
+    let previous = load_settings().map_err(|e| e.to_string())?;
+    save_settings(&settings).map_err(|e| e.to_string())?;
+
+    // Streaks depend on which days count and where each day starts, so recompute
+    // them when either changes
+    if previous.streak != settings.streak
+        || previous.count_only_verified_sessions != settings.count_only_verified_sessions
+        || previous.day_starts_at != settings.day_starts_at
+    {
+        let achievements = recalculate_streak_for_rules().map_err(|e| e.to_string())?;
+        emit_streak_updated(&app, &achievements);
+    }
+
+    Ok(())
 }
 
 #[tauri::command]
@@ -1,11 +1,24 @@
-use chrono::{Datelike, Duration, Local, NaiveDate};
-use serde::Serialize;
+use chrono::{Duration, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
 
 use crate::error::AppError;
 use crate::storage::sessions::{
-    get_daily_summary, get_today_summary, load_sessions_for_date, DailySummary, Session,
+    get_daily_summary, get_today_summary, load_history_page, DailySummary, DateRange, Session,
     SessionStatus,
 };
+use crate::storage::breaks::{self, BreakStats};
+use crate::storage::focus_score::{self, FocusScoreReport};
+use crate::storage::goals::{
+    evaluate_goal, get_goal_progress, is_goal_met, GoalPeriod, GoalProgress,
+};
+use crate::storage::settings::{load_settings, Goal};
+use crate::storage::patterns::{self, FocusPatterns};
+use crate::storage::records::{self, RecordsAndTrends};
+use crate::storage::reports::{write_report, ReportKind, ReportPeriod};
+use crate::storage::stats::{
+    day_abbreviation, get_range_stats, get_year_heatmap, resolve_locale, GroupBy,
+    HeatmapResponse, RangeStats,
+};
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -36,7 +49,7 @@ impl From<DailySummary> for TodayStatsResponse {
 #[tauri::command]
 pub fn get_quick_stats() -> Result<QuickStatsResponse, AppError> {
     let summary = get_today_summary()?;
-    
+
     Ok(QuickStatsResponse {
         current_streak: 0, // Streak calculation not implemented yet (Epic 4)
         today_sessions: summary.complete_count + summary.partial_count,
@@ -89,23 +102,24 @@ pub struct SessionHistoryResponse {
     pub days: Vec<DayHistory>,
     pub has_more: bool,
     pub total_days: u32,
+    /// Pass back as `cursor` to fetch the next (older) page
+    pub next_cursor: Option<String>,
 }
 
+/// Get the next `days` days that have sessions, most recent first.
+/// `cursor` is the `nextCursor` of the previous page; omit it to start from today.
 #[tauri::command]
-pub fn get_session_history(days: Option<u32>) -> Result<SessionHistoryResponse, AppError> {
-    let days_to_fetch = days.unwrap_or(7);
-    let today = Local::now().date_naive();
-
-    let mut day_histories: Vec<DayHistory> = Vec::new();
-    let mut total_days_with_sessions = 0u32;
-
-    for i in 0..days_to_fetch {
-        let date = today - Duration::days(i as i64);
-        let sessions = load_sessions_for_date(date)?;
-
-        if !sessions.is_empty() {
-            total_days_with_sessions += 1;
+pub fn get_session_history(
+    days: Option<u32>,
+    cursor: Option<NaiveDate>,
+) -> Result<SessionHistoryResponse, AppError> {
+    let page_size = days.unwrap_or(7) as usize;
+    let page = load_history_page(cursor, page_size)?;
 
+    let day_histories: Vec<DayHistory> = page
+        .days
+        .iter()
+        .map(|(date, sessions)| {
             let mut total_complete = 0u32;
             let mut total_interrupted = 0u32;
             let mut total_minutes = 0u32;
@@ -124,20 +138,27 @@ pub fn get_session_history(days: Option<u32>) -> Result<SessionHistoryResponse,
 
             session_summaries.sort_by(|a, b| b.start_time.cmp(&a.start_time));
 
-            day_histories.push(DayHistory {
+            DayHistory {
                 date: date.format("%Y-%m-%d").to_string(),
                 sessions: session_summaries,
                 total_complete,
                 total_interrupted,
                 total_minutes,
-            });
-        }
-    }
+            }
+        })
+        .collect();
+
+    let next_cursor = if page.has_more {
+        day_histories.last().map(|d| d.date.clone())
+    } else {
+        None
+    };
 
     Ok(SessionHistoryResponse {
         days: day_histories,
-        has_more: false,
-        total_days: total_days_with_sessions,
+        has_more: page.has_more,
+        total_days: page.total_days,
+        next_cursor,
     })
 }
 
@@ -146,59 +167,155 @@ pub fn get_session_history(days: Option<u32>) -> Result<SessionHistoryResponse,
 #[serde(rename_all = "camelCase")]
 pub struct DayStats {
     pub date: String,
+    /// Abbreviated day name in the configured or system locale
     pub day_name: String,
     pub focus_minutes: u32,
     pub session_count: u32,
     pub is_today: bool,
+    /// Whether the daily goal was met (None when no daily goal is set)
+    pub goal_met: Option<bool>,
+}
+
+/// Which seven days the weekly chart shows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WeekView {
+    /// The last 7 days ending today
+    #[default]
+    Rolling,
+    /// A calendar week starting on the configured `week_start`
+    Calendar,
 }
 
 /// Response for weekly stats
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WeeklyStatsResponse {
+    pub view: WeekView,
+    pub start_date: String,
+    pub end_date: String,
     pub days: Vec<DayStats>,
     pub weekly_total_minutes: u32,
+    /// Daily goal to draw as a line on the chart
+    pub daily_goal: Option<Goal>,
+    /// Weekly goal progress: the shown week in calendar view, the current week in rolling view
+    pub weekly_goal: Option<GoalProgress>,
 }
 
-/// Get weekly statistics for the bar chart (last 7 days)
+/// Get weekly statistics for the bar chart.
+/// `weeks_ago` moves the window back by whole weeks (0 = the week containing today).
 #[tauri::command]
-pub fn get_weekly_stats() -> Result<WeeklyStatsResponse, AppError> {
-    let today = Local::now().date_naive();
+pub fn get_weekly_stats(
+    view: Option<WeekView>,
+    weeks_ago: Option<u32>,
+) -> Result<WeeklyStatsResponse, AppError> {
+    let settings = load_settings()?;
+    let view = view.unwrap_or_default();
+    let locale = resolve_locale(settings.locale.as_deref());
+    let daily_goal = settings.goals.daily;
+
+    let today = settings.logical_date(Local::now().naive_local());
+    let anchor = today - Duration::weeks(weeks_ago.unwrap_or(0) as i64);
+    let start = match view {
+        WeekView::Rolling => anchor - Duration::days(6),
+        WeekView::Calendar => settings.week_start.week_start_of(anchor),
+    };
+    let end = start + Duration::days(6);
+
     let mut days: Vec<DayStats> = Vec::new();
     let mut weekly_total_minutes = 0u32;
+    let mut week_summary = DailySummary::default();
 
-    for i in (0..7).rev() {
-        let date = today - Duration::days(i as i64);
-        let summary = get_daily_summary(date)?;
+    for i in 0..7 {
+        let date = start + Duration::days(i);
+        let summary = if date > today {
+            DailySummary::default()
+        } else {
+            get_daily_summary(date)?
+        };
 
         let session_count = summary.complete_count + summary.partial_count;
         let focus_minutes = summary.total_focus_minutes;
         weekly_total_minutes += focus_minutes;
+        week_summary.merge(&summary);
 
         days.push(DayStats {
             date: date.format("%Y-%m-%d").to_string(),
-            day_name: get_day_abbreviation(date),
+            day_name: day_abbreviation(date, locale),
             focus_minutes,
             session_count,
             is_today: date == today,
+            goal_met: daily_goal.as_ref().map(|goal| is_goal_met(goal, &summary)),
         });
     }
 
+    let weekly_goal = match view {
+        WeekView::Calendar => settings
+            .goals
+            .weekly
+            .map(|goal| evaluate_goal(GoalPeriod::Weekly, &goal, start, &week_summary)),
+        WeekView::Rolling => get_goal_progress()?
+            .into_iter()
+            .find(|progress| progress.period == GoalPeriod::Weekly),
+    };
+
     Ok(WeeklyStatsResponse {
+        view,
+        start_date: start.format("%Y-%m-%d").to_string(),
+        end_date: end.format("%Y-%m-%d").to_string(),
         days,
         weekly_total_minutes,
+        daily_goal,
+        weekly_goal,
     })
 }
 
-/// Get 3-letter day abbreviation
-fn get_day_abbreviation(date: NaiveDate) -> String {
-    match date.weekday() {
-        chrono::Weekday::Mon => "Mon".to_string(),
-        chrono::Weekday::Tue => "Tue".to_string(),
-        chrono::Weekday::Wed => "Wed".to_string(),
-        chrono::Weekday::Thu => "Thu".to_string(),
-        chrono::Weekday::Fri => "Fri".to_string(),
-        chrono::Weekday::Sat => "Sat".to_string(),
-        chrono::Weekday::Sun => "Sun".to_string(),
-    }
+/// Get statistics for any date range, grouped by day, ISO week, month or year
+#[tauri::command]
+pub fn get_stats(range: DateRange, group_by: GroupBy) -> Result<RangeStats, AppError> {
+    get_range_stats(&range, group_by)
+}
+
+/// Get focus minutes per day of a year for the calendar heatmap
+#[tauri::command]
+pub fn get_heatmap(year: i32) -> Result<HeatmapResponse, AppError> {
+    get_year_heatmap(year)
+}
+
+/// Get focus time, completion and interruption rates by hour of day and weekday
+#[tauri::command]
+pub fn get_focus_patterns(range: DateRange) -> Result<FocusPatterns, AppError> {
+    patterns::get_focus_patterns(&range)
+}
+
+/// Get break compliance and rest statistics for a date range
+#[tauri::command]
+pub fn get_break_stats(range: DateRange) -> Result<BreakStats, AppError> {
+    breaks::get_break_stats(&range)
+}
+
+/// Get daily focus scores and when interrupted sessions tend to stop
+#[tauri::command]
+pub fn get_focus_score(range: DateRange) -> Result<FocusScoreReport, AppError> {
+    focus_score::get_focus_score(&range)
+}
+
+/// Get personal bests and this week/month compared with the previous ones
+#[tauri::command]
+pub fn get_records_and_trends() -> Result<RecordsAndTrends, AppError> {
+    records::get_records_and_trends()
+}
+
+/// Get progress toward the configured daily and weekly goals
+#[tauri::command]
+pub fn get_goal_progress_cmd() -> Result<Vec<GoalProgress>, AppError> {
+    get_goal_progress()
+}
+
+/// Write (or rewrite) the weekly or monthly review report of the period containing `date`
+#[tauri::command]
+pub fn generate_report(kind: ReportKind, date: NaiveDate) -> Result<String, AppError> {
+    let week_start = load_settings()?.week_start;
+    let path = write_report(&ReportPeriod::containing(kind, date, week_start))?;
+    Ok(path.to_string_lossy().to_string())
 }
@@ -1,7 +1,22 @@
 use crate::error::AppError;
-use crate::storage::achievements::{get_streak_data, StreakDataResponse};
+use crate::storage::achievements::{
+    get_streak_data, get_streak_freezes, get_streak_history, StreakDataResponse,
+    StreakFreezesResponse, StreakHistoryResponse,
+};
 
 #[tauri::command]
 pub fn get_streak_data_cmd() -> Result<StreakDataResponse, AppError> {
     get_streak_data()
 }
+
+/// Get available streak freezes and the days they protected
+#[tauri::command]
+pub fn get_streak_freezes_cmd() -> Result<StreakFreezesResponse, AppError> {
+    get_streak_freezes()
+}
+
+/// Get every streak run in the history, rebuilding the current and longest streak
+#[tauri::command]
+pub fn get_streak_history_cmd() -> Result<StreakHistoryResponse, AppError> {
+    get_streak_history()
+}
@@ -1,10 +1,14 @@
 use crate::error::TimerError;
-use crate::events::{AchievementUnlockedPayload, SessionCompletePayload, SessionSavedPayload, StreakUpdatedPayload, TimerTickPayload};
-use crate::notifications::{send_achievement_unlocked_notification, send_break_complete_notification, send_focus_complete_notification};
+use crate::events::{AchievementUnlockedPayload, GoalReachedPayload, LevelUpPayload, SessionCompletePayload, SessionSavedPayload, StreakFrozenPayload, StreakUpdatedPayload, TimerTickPayload};
+use crate::notifications::{send_achievement_unlocked_notification, send_break_complete_notification, send_focus_complete_notification, send_goal_reached_notification, send_level_up_notification};
 use crate::state::{TimerState, TimerStateWrapper, TimerStatus, BREAK_DURATION_SECONDS, FOCUS_DURATION_SECONDS};
 use crate::storage::recovery::{create_recovery_file, delete_recovery_file, update_recovery_tick};
-use crate::storage::sessions::{save_session, Session, SessionStatus, SessionType, get_today_summary};
-use crate::storage::achievements::{update_streak_on_completion, check_and_unlock_achievements, AchievementTier};
+use crate::storage::sessions::{save_verified_session, DateRange, Session, SessionStatus, SessionType, get_today_summary};
+use crate::storage::breaks::{get_break_stats, BreakStats};
+use crate::storage::goals::{check_goals_reached, get_goal_progress, GoalPeriod, GoalProgress};
+use crate::storage::settings::{logical_today, GoalMetric};
+use crate::storage::achievements::{update_streak_on_completion, check_achievements_and_progress, Achievement, AchievementCheck, AchievementTier, AchievementsData, FrozenDay};
+use crate::storage::progression::LevelUp;
 use crate::tray::update_tray_icon;
 use chrono::{Local, Utc};
 use std::sync::Arc;
@@ -22,6 +26,31 @@ fn tier_to_string(tier: AchievementTier) -> String {
 }
 
 fn emit_session_saved(app: &AppHandle, session_type: SessionType, status: SessionStatus, duration_seconds: u32) {
+    let goals = get_goal_progress().unwrap_or_else(|e| {
+        eprintln!("Failed to compute goal progress: {}", e);
+        Vec::new()
+    });
+
+    let today = logical_today().unwrap_or_else(|_| Local::now().date_naive());
+    let breaks = get_break_stats(&DateRange { start: today, end: today }).unwrap_or_else(|e| {
+        eprintln!("Failed to compute break stats: {}", e);
+        BreakStats::default()
+    });
+
+    let mut streak_update = None;
+    let mut check = AchievementCheck::default();
+    if session_type == SessionType::Focus {
+        if status == SessionStatus::Complete {
+            streak_update = update_streak_on_completion(status).ok();
+        }
+
+        // Interrupted sessions still add focus minutes
+        match check_achievements_and_progress() {
+            Ok(result) => check = result,
+            Err(e) => eprintln!("Failed to check achievements: {}", e),
+        }
+    }
+
     if let Ok(summary) = get_today_summary() {
         let payload = SessionSavedPayload {
             session_type: session_type.as_str().to_string(),
@@ -30,37 +59,99 @@ fn emit_session_saved(app: &AppHandle, session_type: SessionType, status: Sessio
             complete_count: summary.complete_count,
             partial_count: summary.partial_count,
             total_focus_minutes: summary.total_focus_minutes,
+            goals: goals.clone(),
+            breaks,
+            achievement_progress: check.progress,
         };
         let _ = app.emit("SessionSaved", payload);
     }
+
+    match check_goals_reached(&goals) {
+        Ok(reached) => emit_goals_reached(app, reached),
+        Err(e) => eprintln!("Failed to record reached goals: {}", e),
+    }
     
-    if status == SessionStatus::Complete && session_type == SessionType::Focus {
-        if let Ok(achievements) = update_streak_on_completion(status) {
-            let streak_payload = StreakUpdatedPayload {
-                current_streak: achievements.current_streak,
-                longest_streak: achievements.longest_streak,
-            };
-            let _ = app.emit("StreakUpdated", streak_payload);
-        }
+    if let Some((achievements, newly_frozen)) = streak_update {
+        emit_streak_frozen(app, newly_frozen, &achievements);
+        emit_streak_updated(app, &achievements);
+    }
+
+    emit_achievements_unlocked(app, check.unlocked);
+    if let Some(level_up) = check.level_up {
+        emit_level_up(app, level_up);
+    }
+}
+
+fn emit_goals_reached(app: &AppHandle, reached: Vec<GoalProgress>) {
+    for goal in reached {
+        let period = match goal.period {
+            GoalPeriod::Daily => "Daily",
+            GoalPeriod::Weekly => "Weekly",
+        };
+        let unit = match goal.metric {
+            GoalMetric::FocusMinutes => "focus minutes",
+            GoalMetric::CompletedSessions => "sessions",
+        };
+        send_goal_reached_notification(app, &format!("{} goal of {} {} reached", period, goal.target, unit));
+
+        let payload = GoalReachedPayload {
+            period: goal.period.as_str().to_string(),
+            period_start: goal.period_start,
+            metric: goal.metric.as_str().to_string(),
+            target: goal.target,
+            current: goal.current,
+        };
+        let _ = app.emit("GoalReached", payload);
+    }
+}
+
+pub(crate) fn emit_streak_updated(app: &AppHandle, achievements: &AchievementsData) {
+    let streak_payload = StreakUpdatedPayload {
+        current_streak: achievements.current_streak,
+        longest_streak: achievements.longest_streak,
+    };
+    let _ = app.emit("StreakUpdated", streak_payload);
+}
+
+pub(crate) fn emit_streak_frozen(app: &AppHandle, newly_frozen: Vec<FrozenDay>, achievements: &AchievementsData) {
+    for frozen in newly_frozen {
+        let payload = StreakFrozenPayload {
+            date: frozen.date,
+            frozen_at: frozen.frozen_at,
+            remaining_freezes: achievements.streak_freezes,
+            current_streak: achievements.current_streak,
+        };
+        let _ = app.emit("StreakFrozen", payload);
+    }
+}
+
+pub(crate) fn emit_achievements_unlocked(app: &AppHandle, newly_unlocked: Vec<(Achievement, String)>) {
+    for (achievement, unlocked_at) in newly_unlocked {
+        let payload = AchievementUnlockedPayload {
+            id: achievement.id,
+            title: achievement.title.clone(),
+            description: achievement.description,
+            tier: tier_to_string(achievement.tier),
+            icon: achievement.icon,
+            unlocked_at,
+        };
+        let _ = app.emit("AchievementUnlocked", payload);
         
-        if let Ok(newly_unlocked) = check_and_unlock_achievements() {
-            for (achievement, unlocked_at) in newly_unlocked {
-                let payload = AchievementUnlockedPayload {
-                    id: achievement.id,
-                    title: achievement.title.clone(),
-                    description: achievement.description,
-                    tier: tier_to_string(achievement.tier),
-                    icon: achievement.icon,
-                    unlocked_at,
-                };
-                let _ = app.emit("AchievementUnlocked", payload);
-                
-                send_achievement_unlocked_notification(app, &achievement.title);
-            }
-        }
+        send_achievement_unlocked_notification(app, &achievement.title);
     }
 }
 
+pub(crate) fn emit_level_up(app: &AppHandle, level_up: LevelUp) {
+    let payload = LevelUpPayload {
+        level: level_up.level,
+        previous_level: level_up.previous_level,
+        xp: level_up.xp,
+    };
+    let _ = app.emit("LevelUp", payload);
+
+    send_level_up_notification(app, level_up.level);
+}
+
 #[tauri::command]
 pub fn start_timer(
     state: State<'_, TimerStateWrapper>,
@@ -145,6 +236,7 @@ pub fn stop_timer(state: State<'_, TimerStateWrapper>, app: AppHandle) -> Result
     // Save interrupted session if timer was active (focus or paused focus)
     let is_active_focus_session = matches!(timer.status, TimerStatus::Focus | TimerStatus::Paused) 
         && timer.paused_status != Some(TimerStatus::Break);
+    let mut saved = None;
     
     if let (Some(start_time), true) = (timer.session_start_time, is_active_focus_session) {
         let session_type = if timer.status == TimerStatus::Paused {
@@ -164,12 +256,13 @@ pub fn stop_timer(state: State<'_, TimerStateWrapper>, app: AppHandle) -> Result
         let start_local = start_time.with_timezone(&Local);
         let end_local = Local::now();
         let duration_seconds = (end_local - start_local).num_seconds().max(0) as u32;
-        let session = Session::new(start_local, end_local, SessionStatus::Interrupted, session_type);
+        let mut session = Session::new(start_local, end_local, SessionStatus::Interrupted, session_type);
+        session.pauses = timer.pause_count;
         
-        if let Err(e) = save_session(session) {
+        if let Err(e) = save_verified_session(session) {
             eprintln!("Failed to save interrupted session: {}", e);
         } else {
-            emit_session_saved(&app, session_type, SessionStatus::Interrupted, duration_seconds);
+            saved = Some((session_type, duration_seconds));
         }
     }
 
@@ -180,7 +273,15 @@ pub fn stop_timer(state: State<'_, TimerStateWrapper>, app: AppHandle) -> Result
         eprintln!("Failed to delete recovery file: {}", e);
     }
 
-    Ok(timer.clone())
+    let stopped = timer.clone();
+    drop(timer);
+
+    // Streak, achievement and XP updates read the history; keep them outside the lock
+    if let Some((session_type, duration_seconds)) = saved {
+        emit_session_saved(&app, session_type, SessionStatus::Interrupted, duration_seconds);
+    }
+
+    Ok(stopped)
 }
 
 #[tauri::command]
@@ -236,6 +337,7 @@ fn spawn_timer_thread(
 
             if timer.is_complete() {
                 let session_type = timer.status.as_str().to_string();
+                let mut saved = None;
                 let duration = match timer.status {
                     TimerStatus::Focus => FOCUS_DURATION_SECONDS,
                     TimerStatus::Break => BREAK_DURATION_SECONDS,
@@ -250,12 +352,13 @@ fn spawn_timer_thread(
                     };
                     let start_local = start_time.with_timezone(&Local);
                     let end_local = Local::now();
-                    let session = Session::new(start_local, end_local, SessionStatus::Complete, storage_session_type);
+                    let mut session = Session::new(start_local, end_local, SessionStatus::Complete, storage_session_type);
+                    session.pauses = timer.pause_count;
                     
-                    if let Err(e) = save_session(session) {
+                    if let Err(e) = save_verified_session(session) {
                         eprintln!("Failed to save completed session: {}", e);
                     } else {
-                        emit_session_saved(&app, storage_session_type, SessionStatus::Complete, duration);
+                        saved = Some(storage_session_type);
                     }
                 }
                 
@@ -270,7 +373,8 @@ fn spawn_timer_thread(
                 };
                 let _ = app.emit("SessionComplete", complete_payload);
 
-                if timer.status == TimerStatus::Focus {
+                let finished = timer.status != TimerStatus::Focus;
+                if !finished {
                     send_focus_complete_notification(&app);
                     timer.start_break();
                     update_tray_icon(&app, "break");
@@ -290,6 +394,15 @@ fn spawn_timer_thread(
                         status: "idle".to_string(),
                     };
                     let _ = app.emit("TimerTick", tick_payload);
+                }
+
+                // Streak, achievement and XP updates read the history; keep them
+                // outside the lock so commands and the next tick aren't held up
+                drop(timer);
+                if let Some(storage_session_type) = saved {
+                    emit_session_saved(&app, storage_session_type, SessionStatus::Complete, duration);
+                }
+                if finished {
                     break;
                 }
             }
@@ -1,5 +1,9 @@
 use serde::Serialize;
 
+use crate::storage::achievements::AchievementProgressDelta;
+use crate::storage::breaks::BreakStats;
+use crate::storage::goals::GoalProgress;
+
 /// Event payload for timer tick updates sent to frontend
 #[derive(Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -27,6 +31,23 @@ pub struct SessionSavedPayload {
     pub complete_count: u32,
     pub partial_count: u32,
     pub total_focus_minutes: u32,
+    /// Progress toward the configured daily and weekly goals
+    pub goals: Vec<GoalProgress>,
+    /// Today's break statistics
+    pub breaks: BreakStats,
+    /// Locked achievements whose progress changed with this session
+    pub achievement_progress: Vec<AchievementProgressDelta>,
+}
+
+/// Event payload for goal reached (sent the first time a goal is met in its period)
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoalReachedPayload {
+    pub period: String,
+    pub period_start: String,
+    pub metric: String,
+    pub target: u32,
+    pub current: u32,
 }
 
 /// Event payload for streak updates (sent to frontend when streak changes)
@@ -37,6 +58,25 @@ pub struct StreakUpdatedPayload {
     pub longest_streak: u32,
 }
 
+/// Event payload for a missed day protected by a streak freeze
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreakFrozenPayload {
+    pub date: String,
+    pub frozen_at: String,
+    pub remaining_freezes: u32,
+    pub current_streak: u32,
+}
+
+/// Event payload for level up (sent when earned XP reaches a new level)
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelUpPayload {
+    pub level: u32,
+    pub previous_level: u32,
+    pub xp: u32,
+}
+
 /// Event payload for achievement unlocked (sent to frontend for celebration)
 #[derive(Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
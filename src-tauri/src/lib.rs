@@ -2,19 +2,24 @@ mod commands;
 mod error;
 mod events;
 mod notifications;
+mod scheduler;
 mod state;
 mod storage;
 mod tray;
 
 use commands::achievements;
 use commands::app;
+use commands::backup;
+use commands::export;
+use commands::import;
 use commands::session;
 use commands::settings;
 use commands::stats;
 use commands::streak;
 use commands::timer;
 use state::TimerStateWrapper;
-use storage::achievements::recalculate_streak_on_startup;
+use storage::achievements::{evaluate_achievements_from_history, recalculate_streak_on_startup};
+use storage::conflicts::{recalculate_after_merge, resolve_sync_conflicts};
 use storage::recovery::check_and_recover_session;
 use storage::settings::initialize_settings;
 
@@ -37,9 +42,34 @@ pub fn run() {
         );
     }
 
+    match resolve_sync_conflicts() {
+        Ok(report) if report.conflict_files > 0 => {
+            eprintln!(
+                "Merged {} sync conflict file(s), {} session(s) added",
+                report.conflict_files, report.sessions_added
+            );
+            if report.sessions_added > 0 {
+                if let Err(e) = recalculate_after_merge(&report) {
+                    eprintln!("Warning: Failed to update session totals after merge: {}", e);
+                }
+            }
+        }
+        Ok(_) => {}
+        Err(e) => eprintln!("Warning: Failed to resolve sync conflicts: {}", e),
+    }
+
     if let Err(e) = recalculate_streak_on_startup() {
         eprintln!("Warning: Failed to recalculate streak: {}", e);
     }
+
+    // Definitions may have been added or sessions imported since the last run
+    match evaluate_achievements_from_history() {
+        Ok(unlocked) if !unlocked.is_empty() => {
+            eprintln!("Unlocked {} achievement(s) earned in past sessions", unlocked.len())
+        }
+        Ok(_) => {}
+        Err(e) => eprintln!("Warning: Failed to evaluate achievements: {}", e),
+    }
     
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
@@ -50,6 +80,7 @@ pub fn run() {
             if let Err(e) = tray::setup_tray(app.handle()) {
                 eprintln!("Failed to setup tray: {}", e);
             }
+            scheduler::spawn_scheduler_thread(app.handle().clone());
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -63,18 +94,40 @@ pub fn run() {
             stats::get_today_stats,
             stats::get_session_history,
             stats::get_weekly_stats,
+            stats::get_stats,
+            stats::get_heatmap,
+            stats::get_focus_patterns,
+            stats::get_records_and_trends,
+            stats::get_goal_progress_cmd,
+            stats::generate_report,
+            stats::get_break_stats,
+            stats::get_focus_score,
             session::save_session_cmd,
             session::get_sessions_for_date,
             session::get_today_sessions,
             session::save_completed_session,
             session::save_interrupted_session,
+            session::resolve_sync_conflicts_cmd,
+            session::verify_history,
             settings::get_settings,
             settings::update_settings,
             settings::pick_storage_folder,
             settings::change_storage_location,
             settings::reset_storage_location,
+            export::export_sessions,
+            import::pick_import_file,
+            import::import_sessions,
+            backup::create_backup,
+            backup::pick_backup_file,
+            backup::inspect_backup,
+            backup::restore_backup,
             streak::get_streak_data_cmd,
+            streak::get_streak_freezes_cmd,
+            streak::get_streak_history_cmd,
             achievements::get_achievements,
+            achievements::get_next_achievements,
+            achievements::evaluate_achievements,
+            achievements::get_progression,
             achievements::get_total_sessions,
             app::quit_app,
         ])
@@ -35,3 +35,39 @@ pub fn send_achievement_unlocked_notification(app: &tauri::AppHandle, title: &st
         eprintln!("Failed to send achievement unlocked notification: {}", e);
     }
 }
+
+pub fn send_goal_reached_notification(app: &tauri::AppHandle, body: &str) {
+    if let Err(e) = app
+        .notification()
+        .builder()
+        .title("Goal Reached!")
+        .body(body)
+        .show()
+    {
+        eprintln!("Failed to send goal reached notification: {}", e);
+    }
+}
+
+pub fn send_streak_reminder_notification(app: &tauri::AppHandle, body: &str) {
+    if let Err(e) = app
+        .notification()
+        .builder()
+        .title("Streak at Risk")
+        .body(body)
+        .show()
+    {
+        eprintln!("Failed to send streak reminder notification: {}", e);
+    }
+}
+
+pub fn send_level_up_notification(app: &tauri::AppHandle, level: u32) {
+    if let Err(e) = app
+        .notification()
+        .builder()
+        .title("Level Up!")
+        .body(format!("You reached level {}", level))
+        .show()
+    {
+        eprintln!("Failed to send level up notification: {}", e);
+    }
+}
@@ -0,0 +1,90 @@
+//! Background scheduler
+//!
+//! Runs periodic housekeeping jobs on a dedicated thread, separate from the timer thread.
+//! The thread wakes up every few minutes so evening reminders go out on time, while
+//! the heavier housekeeping jobs only run once an hour.
+
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+
+use crate::commands::timer::{emit_achievements_unlocked, emit_streak_frozen, emit_streak_updated};
+use crate::notifications::send_streak_reminder_notification;
+use crate::storage::achievements::{evaluate_achievements_from_history, refresh_streak};
+use crate::storage::backup::run_auto_backup_if_due;
+use crate::storage::conflicts::{recalculate_after_merge, resolve_sync_conflicts};
+use crate::storage::reminders::check_streak_reminder;
+use crate::storage::reports::generate_due_reports;
+
+/// How often the scheduler wakes up to check for due reminders
+const SCHEDULER_TICK: Duration = Duration::from_secs(5 * 60);
+
+/// How often the housekeeping jobs run
+const SCHEDULER_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+fn send_due_reminders(app: &AppHandle) {
+    match check_streak_reminder() {
+        Ok(Some(message)) => send_streak_reminder_notification(app, &message),
+        Ok(None) => {}
+        Err(e) => eprintln!("Warning: Failed to check streak reminder: {}", e),
+    }
+}
+
+fn run_due_jobs(app: &AppHandle) {
+    // Conflict copies can appear at any time while a sync tool is running
+    match resolve_sync_conflicts() {
+        Ok(report) if report.sessions_added > 0 => {
+            match recalculate_after_merge(&report) {
+                Ok(achievements) => emit_streak_updated(app, &achievements),
+                Err(e) => eprintln!("Warning: Failed to recalculate streak after merge: {}", e),
+            }
+            match evaluate_achievements_from_history() {
+                Ok(unlocked) => emit_achievements_unlocked(app, unlocked),
+                Err(e) => eprintln!("Warning: Failed to evaluate achievements after merge: {}", e),
+            }
+        }
+        Ok(_) => {}
+        Err(e) => eprintln!("Warning: Failed to resolve sync conflicts: {}", e),
+    }
+
+    // A day without sessions may have just ended; spend a streak freeze if one covers it
+    match refresh_streak() {
+        Ok((achievements, newly_frozen)) if !newly_frozen.is_empty() => {
+            emit_streak_frozen(app, newly_frozen, &achievements);
+            emit_streak_updated(app, &achievements);
+        }
+        Ok(_) => {}
+        Err(e) => eprintln!("Warning: Failed to refresh streak: {}", e),
+    }
+
+    match run_auto_backup_if_due() {
+        Ok(Some(path)) => eprintln!("Automatic backup written to {:?}", path),
+        Ok(None) => {}
+        Err(e) => eprintln!("Warning: Automatic backup failed: {}", e),
+    }
+
+    // Reviews of the week and month that just ended
+    match generate_due_reports() {
+        Ok(paths) => {
+            for path in paths {
+                eprintln!("Review report written to {:?}", path);
+            }
+        }
+        Err(e) => eprintln!("Warning: Failed to write review reports: {}", e),
+    }
+}
+
+pub fn spawn_scheduler_thread(app: AppHandle) {
+    thread::spawn(move || {
+        let mut last_run: Option<Instant> = None;
+        loop {
+            if !matches!(last_run, Some(at) if at.elapsed() < SCHEDULER_INTERVAL) {
+                run_due_jobs(&app);
+                last_run = Some(Instant::now());
+            }
+            // After the housekeeping, so the streak is up to date
+            send_due_reminders(&app);
+            thread::sleep(SCHEDULER_TICK);
+        }
+    });
+}
@@ -35,6 +35,9 @@ pub struct TimerState {
     pub session_start_time: Option<DateTime<Utc>>,
     #[serde(skip)]
     pub paused_status: Option<TimerStatus>,
+    /// Pauses in the current session, recorded with the saved session
+    #[serde(skip)]
+    pub pause_count: u32,
 }
 
 impl Default for TimerState {
@@ -44,6 +47,7 @@ impl Default for TimerState {
             remaining_seconds: 0,
             session_start_time: None,
             paused_status: None,
+            pause_count: 0,
         }
     }
 }
@@ -54,6 +58,7 @@ impl TimerState {
         self.remaining_seconds = FOCUS_DURATION_SECONDS;
         self.session_start_time = Some(Utc::now());
         self.paused_status = None;
+        self.pause_count = 0;
     }
 
     pub fn start_break(&mut self) {
@@ -61,6 +66,7 @@ impl TimerState {
         self.remaining_seconds = BREAK_DURATION_SECONDS;
         self.session_start_time = Some(Utc::now());
         self.paused_status = None;
+        self.pause_count = 0;
     }
 
     pub fn pause(&mut self) -> bool {
@@ -68,6 +74,7 @@ impl TimerState {
             TimerStatus::Focus | TimerStatus::Break => {
                 self.paused_status = Some(self.status);
                 self.status = TimerStatus::Paused;
+                self.pause_count += 1;
                 true
             }
             _ => false,
@@ -89,6 +96,7 @@ impl TimerState {
         self.remaining_seconds = 0;
         self.session_start_time = None;
         self.paused_status = None;
+        self.pause_count = 0;
     }
 
     pub fn tick(&mut self) -> bool {
@@ -264,4 +272,19 @@ mod tests {
 
         assert_eq!(state.session_duration(), 300);
     }
+
+    #[test]
+    fn test_pause_count_is_per_session() {
+        let mut state = TimerState::default();
+        state.start_focus();
+        state.pause();
+        state.resume();
+        state.pause();
+
+        assert_eq!(state.pause_count, 2);
+
+        state.resume();
+        state.start_break();
+        assert_eq!(state.pause_count, 0);
+    }
 }
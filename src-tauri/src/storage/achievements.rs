@@ -3,15 +3,29 @@
 //! Handles reading and writing achievement data including streaks to `achievements.json`.
 //! Files are stored in platform-specific app data directories (AR9 specification).
 
-use chrono::{Local, NaiveDate, Utc};
+use chrono::{Local, NaiveDate, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::{self, File};
 use std::io::Write;
 use std::path::PathBuf;
 
 use crate::error::AppError;
-use crate::storage::sessions::{get_sessions_directory, SessionStatus};
-use crate::storage::settings::get_data_directory;
+use crate::storage::goals::{goal_value, is_goal_met};
+use crate::storage::integrity::{keep_verified_sessions, load_or_create_integrity_key};
+use crate::storage::lock::lock_storage;
+use crate::storage::progression::{compute_progression, record_progression, LevelUp};
+use crate::storage::sessions::{
+    get_session_file_path, list_session_dates, load_daily_file, load_sessions_in_range,
+    DailySummary, DateRange, Session, SessionStatus, SessionType,
+};
+use crate::storage::settings::{
+    get_data_directory, load_settings, logical_today, time_since_day_start, Settings, StreakRules,
+};
+use crate::storage::stats::{
+    load_daily_summaries, load_day_digests, load_verified_daily_summaries,
+    load_verified_day_digests, DayDigest,
+};
 
 /// Achievement tier levels
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -25,10 +39,26 @@ pub enum AchievementTier {
 
 /// Achievement requirement type
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(tag = "type", content = "value", rename_all = "lowercase")]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
 pub enum AchievementRequirement {
+    /// Current streak of at least N days
     Streak(u32),
+    /// N completed sessions in total
     Sessions(u32),
+    /// N minutes of focus in total, interrupted sessions included
+    FocusMinutes(u32),
+    /// N completed sessions in a single day
+    SessionsInDay(u32),
+    /// `count` completed sessions started before `time` (early bird)
+    StartedBefore { time: NaiveTime, count: u32 },
+    /// `count` completed sessions started at or after `time` (night owl)
+    StartedAfter { time: NaiveTime, count: u32 },
+    /// N full weeks meeting the weekly goal
+    GoalWeeks(u32),
+    /// N days with completed sessions and no interrupted one
+    PerfectDays(u32),
+    /// A streak of N days built after a previous streak was broken
+    Comeback(u32),
 }
 
 /// Achievement definition
@@ -63,76 +93,116 @@ pub struct AchievementWithStatus {
     pub requirement: AchievementRequirement,
     pub unlocked: bool,
     pub unlocked_at: Option<String>,
+    /// Progress toward the requirement, for locked achievements only
+    pub progress: Option<AchievementProgress>,
 }
 
-/// All defined achievements
+/// Progress toward an achievement's requirement, e.g. 37 / 50 sessions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AchievementProgress {
+    /// Current value, capped at `target`
+    pub current: u32,
+    pub target: u32,
+    pub percent: u32,
+    /// Unit of `current` and `target`: "days", "sessions", "minutes" or "weeks"
+    pub unit: String,
+}
+
+/// Change in progress toward a locked achievement since it was last reported
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AchievementProgressDelta {
+    pub id: String,
+    pub previous: u32,
+    pub current: u32,
+    pub target: u32,
+    pub unit: String,
+}
+
+/// Achievement definitions shipped with the app
+const BUNDLED_ACHIEVEMENTS: &str = include_str!("../../resources/achievements.json");
+
+/// Optional user file in the data directory adding custom achievements
+const CUSTOM_ACHIEVEMENTS_FILE: &str = "custom_achievements.json";
+
+/// Icon names the frontend can render (lucide icons)
+pub const ACHIEVEMENT_ICONS: &[&str] = &[
+    "Award", "Book", "Brain", "Calendar", "CheckCircle", "Clock", "Coffee", "Crown", "Flame",
+    "Gem", "Heart", "Leaf", "Medal", "Moon", "Mountain", "Rocket", "Shield", "Sparkles", "Star",
+    "Sun", "Target", "Timer", "Trophy", "Zap",
+];
+
+/// Parse a JSON array of achievement definitions
+pub fn parse_achievement_definitions(content: &str) -> Result<Vec<Achievement>, AppError> {
+    serde_json::from_str(content).map_err(|e| {
+        AppError::ParseError(format!("Failed to parse achievement definitions: {}", e))
+    })
+}
+
+/// Check that ids are non-empty and unique, also against `existing`, and that
+/// every icon is a known one
+pub fn validate_achievement_definitions(
+    definitions: &[Achievement],
+    existing: &[Achievement],
+) -> Result<(), AppError> {
+    let mut ids: BTreeSet<&str> = existing.iter().map(|a| a.id.as_str()).collect();
+    for achievement in definitions {
+        if achievement.id.trim().is_empty() {
+            return Err(AppError::ParseError(
+                "Achievement definition with an empty id".to_string(),
+            ));
+        }
+        if !ids.insert(achievement.id.as_str()) {
+            return Err(AppError::ParseError(format!(
+                "Duplicate achievement id: {}",
+                achievement.id
+            )));
+        }
+        if !ACHIEVEMENT_ICONS.contains(&achievement.icon.as_str()) {
+            return Err(AppError::ParseError(format!(
+                "Unknown icon \"{}\" for achievement {}",
+                achievement.icon, achievement.id
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Achievements shipped with the app
+pub fn bundled_achievements() -> Vec<Achievement> {
+    parse_achievement_definitions(BUNDLED_ACHIEVEMENTS)
+        .expect("bundled achievements.json is valid")
+}
+
+pub fn get_custom_achievements_file_path() -> Result<PathBuf, AppError> {
+    Ok(get_data_directory()?.join(CUSTOM_ACHIEVEMENTS_FILE))
+}
+
+/// Bundled achievements followed by the validated custom ones from the data directory
+pub fn load_achievement_definitions() -> Result<Vec<Achievement>, AppError> {
+    let mut definitions = bundled_achievements();
+
+    let custom_path = get_custom_achievements_file_path()?;
+    if custom_path.exists() {
+        let content = fs::read_to_string(&custom_path).map_err(|e| {
+            AppError::StorageError(format!("Failed to read custom achievements file: {}", e))
+        })?;
+        let custom = parse_achievement_definitions(&content)?;
+        validate_achievement_definitions(&custom, &definitions)?;
+        definitions.extend(custom);
+    }
+
+    Ok(definitions)
+}
+
+/// All defined achievements. An invalid custom achievements file is ignored so the
+/// bundled achievements keep working.
 pub fn get_all_achievements() -> Vec<Achievement> {
-    vec![
-        Achievement {
-            id: "first_session".to_string(),
-            title: "First Focus".to_string(),
-            description: "Complete your first session".to_string(),
-            icon: "Flame".to_string(),
-            tier: AchievementTier::Bronze,
-            requirement: AchievementRequirement::Sessions(1),
-        },
-        Achievement {
-            id: "streak_7".to_string(),
-            title: "Week Warrior".to_string(),
-            description: "7-day streak".to_string(),
-            icon: "Zap".to_string(),
-            tier: AchievementTier::Silver,
-            requirement: AchievementRequirement::Streak(7),
-        },
-        Achievement {
-            id: "streak_14".to_string(),
-            title: "Fortnight Focus".to_string(),
-            description: "14-day streak".to_string(),
-            icon: "Award".to_string(),
-            tier: AchievementTier::Gold,
-            requirement: AchievementRequirement::Streak(14),
-        },
-        Achievement {
-            id: "streak_30".to_string(),
-            title: "Monthly Master".to_string(),
-            description: "30-day streak".to_string(),
-            icon: "Crown".to_string(),
-            tier: AchievementTier::Platinum,
-            requirement: AchievementRequirement::Streak(30),
-        },
-        Achievement {
-            id: "sessions_10".to_string(),
-            title: "Getting Started".to_string(),
-            description: "10 total sessions".to_string(),
-            icon: "Target".to_string(),
-            tier: AchievementTier::Bronze,
-            requirement: AchievementRequirement::Sessions(10),
-        },
-        Achievement {
-            id: "sessions_50".to_string(),
-            title: "Half Century".to_string(),
-            description: "50 total sessions".to_string(),
-            icon: "Trophy".to_string(),
-            tier: AchievementTier::Silver,
-            requirement: AchievementRequirement::Sessions(50),
-        },
-        Achievement {
-            id: "sessions_100".to_string(),
-            title: "Centurion".to_string(),
-            description: "100 total sessions".to_string(),
-            icon: "Medal".to_string(),
-            tier: AchievementTier::Gold,
-            requirement: AchievementRequirement::Sessions(100),
-        },
-        Achievement {
-            id: "sessions_500".to_string(),
-            title: "Focus Legend".to_string(),
-            description: "500 total sessions".to_string(),
-            icon: "Crown".to_string(),
-            tier: AchievementTier::Platinum,
-            requirement: AchievementRequirement::Sessions(500),
-        },
-    ]
+    load_achievement_definitions().unwrap_or_else(|e| {
+        eprintln!("Warning: Ignoring custom achievements: {}", e);
+        bundled_achievements()
+    })
 }
 
 /// Check if an achievement is already unlocked
@@ -140,19 +210,352 @@ fn is_already_unlocked(achievement_id: &str, unlocked: &[UnlockedAchievement]) -
     unlocked.iter().any(|u| u.id == achievement_id)
 }
 
-/// Check if an achievement's condition is met
-fn is_condition_met(requirement: &AchievementRequirement, current_streak: u32, total_sessions: u32) -> bool {
+/// Aggregated history that achievement requirements are evaluated against
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AchievementStats {
+    pub current_streak: u32,
+    pub total_sessions: u32,
+    pub total_focus_minutes: u32,
+    /// Most completed sessions in a single day
+    pub best_day_sessions: u32,
+    /// Start times of completed focus sessions
+    pub session_starts: Vec<NaiveTime>,
+    /// Day boundary that start times are compared against
+    pub day_starts_at: NaiveTime,
+    /// Past weeks that met the weekly goal
+    pub goal_weeks: u32,
+    pub perfect_days: u32,
+    /// Length of the current streak if an earlier streak was broken before it, else 0
+    pub comeback_streak: u32,
+    /// Days meeting the streak minimum
+    pub streak_days: u32,
+}
+
+impl AchievementStats {
+    /// Aggregate the per-day digests of the session history up to `today`.
+    /// `current_streak` and `total_sessions` come from the stored achievement data,
+    /// except that with `count_only_verified_sessions` the sessions are counted from
+    /// `digests`, which then only cover verified sessions.
+    pub fn from_digests(
+        digests: &[(NaiveDate, DayDigest)],
+        achievements: &AchievementsData,
+        settings: &Settings,
+        today: NaiveDate,
+    ) -> Self {
+        let mut history = HistoryTotals::default();
+        for (date, digest) in digests {
+            history.add_day(*date, digest);
+        }
+
+        let stats = history.stats(settings, &achievements.frozen_dates(), today);
+        AchievementStats {
+            current_streak: achievements.current_streak,
+            total_sessions: if settings.count_only_verified_sessions {
+                stats.total_sessions
+            } else {
+                achievements.total_sessions
+            },
+            ..stats
+        }
+    }
+
+    /// Completed sessions started before `time` in their day
+    fn sessions_started_before(&self, time: NaiveTime) -> u32 {
+        let limit = time_since_day_start(time, self.day_starts_at);
+        self.session_starts
+            .iter()
+            .filter(|start| time_since_day_start(**start, self.day_starts_at) < limit)
+            .count() as u32
+    }
+}
+
+/// Running totals of a session history added in order
+#[derive(Debug, Clone, Default)]
+struct HistoryTotals {
+    summaries: BTreeMap<NaiveDate, DailySummary>,
+    complete_sessions: u32,
+    session_starts: Vec<NaiveTime>,
+}
+
+impl HistoryTotals {
+    fn add_session(&mut self, date: NaiveDate, session: &Session) {
+        if session.session_type != SessionType::Focus {
+            return;
+        }
+        if session.status == SessionStatus::Complete {
+            self.complete_sessions += 1;
+            self.session_starts.push(session.start_time.time());
+        }
+        self.summaries
+            .entry(date)
+            .or_default()
+            .merge(&DailySummary::from_sessions(std::slice::from_ref(session)));
+    }
+
+    fn add_day(&mut self, date: NaiveDate, digest: &DayDigest) {
+        let summary = &digest.summary;
+        if summary.complete_count == 0 && summary.partial_count == 0 {
+            return;
+        }
+        self.complete_sessions += summary.complete_count;
+        self.session_starts.extend_from_slice(&digest.complete_starts);
+        self.summaries.entry(date).or_default().merge(summary);
+    }
+
+    /// Stats of the sessions added so far, as seen on `today`
+    fn stats(
+        &self,
+        settings: &Settings,
+        frozen: &BTreeSet<NaiveDate>,
+        today: NaiveDate,
+    ) -> AchievementStats {
+        let mut stats = AchievementStats {
+            total_sessions: self.complete_sessions,
+            session_starts: self.session_starts.clone(),
+            day_starts_at: settings.day_starts_at,
+            ..Default::default()
+        };
+
+        for summary in self.summaries.values() {
+            stats.total_focus_minutes += summary.total_focus_minutes;
+            stats.best_day_sessions = stats.best_day_sessions.max(summary.complete_count);
+            if summary.complete_count > 0 && summary.partial_count == 0 {
+                stats.perfect_days += 1;
+            }
+        }
+
+        if let Some(goal) = &settings.goals.weekly {
+            let current_week = settings.week_start.week_start_of(today);
+            let mut weeks: BTreeMap<NaiveDate, DailySummary> = BTreeMap::new();
+            for (date, summary) in self.summaries.iter().filter(|(date, _)| **date < current_week) {
+                weeks
+                    .entry(settings.week_start.week_start_of(*date))
+                    .or_default()
+                    .merge(summary);
+            }
+            stats.goal_weeks = weeks.values().filter(|week| is_goal_met(goal, week)).count() as u32;
+        }
+
+        let streaks = compute_streaks(&self.summaries, &settings.streak, frozen, today);
+        stats.current_streak = streaks.current_length();
+        stats.streak_days = streaks.runs.iter().map(|run| run.length).sum();
+        if let Some(current) = &streaks.current {
+            if streaks.runs.iter().any(|run| run.end < current.start) {
+                stats.comeback_streak = current.length;
+            }
+        }
+
+        stats
+    }
+}
+
+/// Every day of the session history. With `count_only_verified_sessions`, only
+/// sessions that pass verification are kept.
+fn load_history_days(settings: &Settings) -> Result<Vec<(NaiveDate, Vec<Session>)>, AppError> {
+    let dates = list_session_dates()?;
+    let days = match (dates.first(), dates.last()) {
+        (Some(first), Some(last)) => load_sessions_in_range(&DateRange {
+            start: *first,
+            end: *last,
+        })?,
+        _ => Vec::new(),
+    };
+    if !settings.count_only_verified_sessions {
+        return Ok(days);
+    }
+
+    let key = load_or_create_integrity_key()?;
+    days.into_iter()
+        .map(|(date, _)| {
+            let daily_file = load_daily_file(date, &get_session_file_path(date)?)?;
+            Ok((date, keep_verified_sessions(&key, daily_file)))
+        })
+        .collect()
+}
+
+/// Aggregate the whole session history for requirement checks, from the cached
+/// day digests. With `count_only_verified_sessions`, only sessions that pass
+/// verification are counted.
+pub fn load_achievement_stats(
+    achievements: &AchievementsData,
+) -> Result<AchievementStats, AppError> {
+    let settings = load_settings()?;
+    let today = settings.logical_date(Local::now().naive_local());
+    let range = DateRange {
+        start: NaiveDate::MIN,
+        end: NaiveDate::MAX,
+    };
+    let digests = if settings.count_only_verified_sessions {
+        load_verified_day_digests(&range)?
+    } else {
+        load_day_digests(&range)?
+    };
+
+    Ok(AchievementStats::from_digests(&digests, achievements, &settings, today))
+}
+
+/// Replay the history and find the session that first met each of `definitions`.
+/// Returns the earned achievements with the end time of that session, in the order
+/// they were earned.
+///
+/// Each day is evaluated as a whole first; only the days that earn something are
+/// replayed session by session to find the session that earned it.
+pub fn replay_achievements(
+    definitions: &[Achievement],
+    days: &[(NaiveDate, Vec<Session>)],
+    frozen: &BTreeSet<NaiveDate>,
+    settings: &Settings,
+) -> Vec<(Achievement, String)> {
+    let mut pending: Vec<&Achievement> = definitions.iter().collect();
+    let mut earned = Vec::new();
+    let mut history = HistoryTotals::default();
+
+    for (date, sessions) in days {
+        if pending.is_empty() {
+            break;
+        }
+
+        let mut sessions: Vec<&Session> = sessions
+            .iter()
+            .filter(|s| s.session_type == SessionType::Focus)
+            .collect();
+        sessions.sort_by_key(|s| s.end_time);
+
+        let mut end_of_day = history.clone();
+        for session in &sessions {
+            end_of_day.add_session(*date, session);
+        }
+        let stats = end_of_day.stats(settings, frozen, *date);
+        if !pending
+            .iter()
+            .any(|achievement| is_condition_met(&achievement.requirement, &stats))
+        {
+            history = end_of_day;
+            continue;
+        }
+
+        for session in sessions {
+            history.add_session(*date, session);
+
+            let stats = history.stats(settings, frozen, *date);
+            let unlocked_at = session.end_time.with_timezone(&Utc).to_rfc3339();
+            pending.retain(|achievement| {
+                if is_condition_met(&achievement.requirement, &stats) {
+                    earned.push(((*achievement).clone(), unlocked_at.clone()));
+                    false
+                } else {
+                    true
+                }
+            });
+        }
+    }
+
+    earned
+}
+
+impl AchievementRequirement {
+    /// Value the requirement must reach
+    pub fn target(&self) -> u32 {
+        match self {
+            AchievementRequirement::Streak(n)
+            | AchievementRequirement::Sessions(n)
+            | AchievementRequirement::FocusMinutes(n)
+            | AchievementRequirement::SessionsInDay(n)
+            | AchievementRequirement::GoalWeeks(n)
+            | AchievementRequirement::PerfectDays(n)
+            | AchievementRequirement::Comeback(n) => *n,
+            AchievementRequirement::StartedBefore { count, .. }
+            | AchievementRequirement::StartedAfter { count, .. } => *count,
+        }
+    }
+
+    pub fn unit(&self) -> &'static str {
+        match self {
+            AchievementRequirement::Streak(_)
+            | AchievementRequirement::PerfectDays(_)
+            | AchievementRequirement::Comeback(_) => "days",
+            AchievementRequirement::Sessions(_)
+            | AchievementRequirement::SessionsInDay(_)
+            | AchievementRequirement::StartedBefore { .. }
+            | AchievementRequirement::StartedAfter { .. } => "sessions",
+            AchievementRequirement::FocusMinutes(_) => "minutes",
+            AchievementRequirement::GoalWeeks(_) => "weeks",
+        }
+    }
+}
+
+/// Current value of the quantity a requirement counts
+fn requirement_value(requirement: &AchievementRequirement, stats: &AchievementStats) -> u32 {
     match requirement {
-        AchievementRequirement::Streak(n) => current_streak >= *n,
-        AchievementRequirement::Sessions(n) => total_sessions >= *n,
+        AchievementRequirement::Streak(_) => stats.current_streak,
+        AchievementRequirement::Sessions(_) => stats.total_sessions,
+        AchievementRequirement::FocusMinutes(_) => stats.total_focus_minutes,
+        AchievementRequirement::SessionsInDay(_) => stats.best_day_sessions,
+        AchievementRequirement::StartedBefore { time, .. } => stats.sessions_started_before(*time),
+        AchievementRequirement::StartedAfter { time, .. } => {
+            stats.session_starts.len() as u32 - stats.sessions_started_before(*time)
+        }
+        AchievementRequirement::GoalWeeks(_) => stats.goal_weeks,
+        AchievementRequirement::PerfectDays(_) => stats.perfect_days,
+        AchievementRequirement::Comeback(_) => stats.comeback_streak,
     }
 }
 
+/// Check if an achievement's condition is met
+fn is_condition_met(requirement: &AchievementRequirement, stats: &AchievementStats) -> bool {
+    requirement_value(requirement, stats) >= requirement.target()
+}
+
+/// Progress toward a requirement. A zero target counts as complete.
+pub fn achievement_progress(
+    requirement: &AchievementRequirement,
+    stats: &AchievementStats,
+) -> AchievementProgress {
+    let target = requirement.target();
+    let current = requirement_value(requirement, stats).min(target);
+    let percent = if target == 0 {
+        100
+    } else {
+        (current as u64 * 100 / target as u64) as u32
+    };
+
+    AchievementProgress {
+        current,
+        target,
+        percent,
+        unit: requirement.unit().to_string(),
+    }
+}
+
+/// Progress of the locked achievements that changed since `reported`. Achievements
+/// without a reported value have no baseline yet and are skipped.
+pub fn progress_deltas(
+    definitions: &[Achievement],
+    stats: &AchievementStats,
+    unlocked: &[UnlockedAchievement],
+    reported: &BTreeMap<String, u32>,
+) -> Vec<AchievementProgressDelta> {
+    definitions
+        .iter()
+        .filter(|a| !is_already_unlocked(&a.id, unlocked))
+        .filter_map(|a| {
+            let previous = *reported.get(&a.id)?;
+            let progress = achievement_progress(&a.requirement, stats);
+            (progress.current != previous).then(|| AchievementProgressDelta {
+                id: a.id.clone(),
+                previous,
+                current: progress.current,
+                target: progress.target,
+                unit: progress.unit,
+            })
+        })
+        .collect()
+}
+
 /// Check all achievements and return newly unlocked ones
 /// This is called after session completion and streak recalculation
 pub fn check_achievements(
-    current_streak: u32,
-    total_sessions: u32,
+    stats: &AchievementStats,
     unlocked: &[UnlockedAchievement],
 ) -> Vec<Achievement> {
     let all_achievements = get_all_achievements();
@@ -163,7 +566,7 @@ pub fn check_achievements(
             continue;
         }
 
-        if is_condition_met(&achievement.requirement, current_streak, total_sessions) {
+        if is_condition_met(&achievement.requirement, stats) {
             newly_unlocked.push(achievement);
         }
     }
@@ -185,30 +588,88 @@ pub fn process_unlocked_achievements(
             unlocked_at: timestamp.clone(),
         };
         unlocked_records.push(record.clone());
-        
-        if !achievements.unlocked.contains(&achievement.id) {
-            achievements.unlocked.push(achievement.id.clone());
-        }
-        
-        achievements.unlocked_achievements.push(record);
+        record_unlock(achievements, record);
     }
 
     unlocked_records
 }
 
-/// Get all achievements with their unlock status
-pub fn get_achievements_with_status() -> Result<Vec<AchievementWithStatus>, AppError> {
-    let achievements_data = load_achievements()?;
-    let all_achievements = get_all_achievements();
-    
-    let result = all_achievements
+fn record_unlock(achievements: &mut AchievementsData, record: UnlockedAchievement) {
+    if !achievements.unlocked.contains(&record.id) {
+        achievements.unlocked.push(record.id.clone());
+    }
+
+    achievements.unlocked_achievements.push(record);
+}
+
+/// Unlock every achievement the session history has earned, including ones that
+/// were defined, imported or restored after the fact. Each is backdated to the
+/// session that earned it; achievements only met through the stored counters are
+/// unlocked now. Returns the newly unlocked achievements with their unlock time.
+///
+/// The check runs on the cached day digests; the session files are only replayed
+/// when some achievement is actually earned, and the storage lock is only held to
+/// record the unlocks.
+pub fn evaluate_achievements_from_history() -> Result<Vec<(Achievement, String)>, AppError> {
+    let settings = load_settings()?;
+    let achievements = load_achievements()?;
+
+    let stats = load_achievement_stats(&achievements)?;
+    let earned: Vec<Achievement> = get_all_achievements()
+        .into_iter()
+        .filter(|a| !is_already_unlocked(&a.id, &achievements.unlocked_achievements))
+        .filter(|a| is_condition_met(&a.requirement, &stats))
+        .collect();
+    if earned.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let days = load_history_days(&settings)?;
+    let mut newly_unlocked =
+        replay_achievements(&earned, &days, &achievements.frozen_dates(), &settings);
+
+    let now = Utc::now().to_rfc3339();
+    for achievement in earned {
+        if !newly_unlocked.iter().any(|(a, _)| a.id == achievement.id) {
+            newly_unlocked.push((achievement, now.clone()));
+        }
+    }
+
+    let _lock = lock_storage();
+    let mut achievements = load_achievements()?;
+    newly_unlocked.retain(|(a, _)| !is_already_unlocked(&a.id, &achievements.unlocked_achievements));
+    if newly_unlocked.is_empty() {
+        return Ok(Vec::new());
+    }
+    for (achievement, unlocked_at) in &newly_unlocked {
+        record_unlock(
+            &mut achievements,
+            UnlockedAchievement {
+                id: achievement.id.clone(),
+                unlocked_at: unlocked_at.clone(),
+            },
+        );
+    }
+    save_achievements(&achievements)?;
+
+    Ok(newly_unlocked)
+}
+
+/// Attach unlock status and, for locked achievements, progress to the definitions
+pub fn achievements_with_status(
+    definitions: Vec<Achievement>,
+    stats: &AchievementStats,
+    unlocked: &[UnlockedAchievement],
+) -> Vec<AchievementWithStatus> {
+    definitions
         .into_iter()
         .map(|achievement| {
-            let unlocked_record = achievements_data
-                .unlocked_achievements
-                .iter()
-                .find(|u| u.id == achievement.id);
-            
+            let unlocked_record = unlocked.iter().find(|u| u.id == achievement.id);
+            let progress = match unlocked_record {
+                Some(_) => None,
+                None => Some(achievement_progress(&achievement.requirement, stats)),
+            };
+
             AchievementWithStatus {
                 id: achievement.id,
                 title: achievement.title,
@@ -218,11 +679,43 @@ pub fn get_achievements_with_status() -> Result<Vec<AchievementWithStatus>, AppE
                 requirement: achievement.requirement,
                 unlocked: unlocked_record.is_some(),
                 unlocked_at: unlocked_record.map(|u| u.unlocked_at.clone()),
+                progress,
             }
         })
-        .collect();
-    
-    Ok(result)
+        .collect()
+}
+
+/// Get all achievements with their unlock status
+pub fn get_achievements_with_status() -> Result<Vec<AchievementWithStatus>, AppError> {
+    let achievements_data = load_achievements()?;
+    let stats = load_achievement_stats(&achievements_data)?;
+
+    Ok(achievements_with_status(
+        get_all_achievements(),
+        &stats,
+        &achievements_data.unlocked_achievements,
+    ))
+}
+
+/// Locked achievements closest to being unlocked, highest progress first
+pub fn closest_achievements(
+    mut locked: Vec<AchievementWithStatus>,
+    limit: usize,
+) -> Vec<AchievementWithStatus> {
+    locked.retain(|a| !a.unlocked);
+    locked.sort_by_key(|a| {
+        let progress = a.progress.as_ref();
+        let percent = progress.map(|p| p.percent).unwrap_or(0);
+        let remaining = progress.map(|p| p.target - p.current).unwrap_or(u32::MAX);
+        (std::cmp::Reverse(percent), remaining)
+    });
+    locked.truncate(limit);
+    locked
+}
+
+/// The `limit` locked achievements closest to being unlocked
+pub fn get_closest_achievements(limit: usize) -> Result<Vec<AchievementWithStatus>, AppError> {
+    Ok(closest_achievements(get_achievements_with_status()?, limit))
 }
 
 /// Get total lifetime session count
@@ -231,30 +724,61 @@ pub fn get_total_session_count() -> Result<u32, AppError> {
     Ok(achievements.total_sessions)
 }
 
-/// Check for new achievements and process unlocks. Returns newly unlocked achievements with full data.
-pub fn check_and_unlock_achievements() -> Result<Vec<(Achievement, String)>, AppError> {
+/// Outcome of an achievement check
+#[derive(Debug, Default)]
+pub struct AchievementCheck {
+    /// Newly unlocked achievements with their unlock time
+    pub unlocked: Vec<(Achievement, String)>,
+    /// Progress changes of the locked achievements since the last check
+    pub progress: Vec<AchievementProgressDelta>,
+    /// Level reached since the last check
+    pub level_up: Option<LevelUp>,
+}
+
+/// Unlock the achievements whose requirements are now met and record the progress
+/// of the locked ones and the XP earned.
+pub fn check_achievements_and_progress() -> Result<AchievementCheck, AppError> {
+    let _lock = lock_storage();
+    let rules = load_settings()?.progression;
     let mut achievements = load_achievements()?;
-    
-    let newly_unlocked = check_achievements(
-        achievements.current_streak,
-        achievements.total_sessions,
+    let before = achievements.clone();
+    let stats = load_achievement_stats(&achievements)?;
+
+    let newly_unlocked = check_achievements(&stats, &achievements.unlocked_achievements);
+    let records = process_unlocked_achievements(&mut achievements, &newly_unlocked);
+
+    let definitions = get_all_achievements();
+    let deltas = progress_deltas(
+        &definitions,
+        &stats,
         &achievements.unlocked_achievements,
+        &achievements.reported_progress,
     );
-    
-    if newly_unlocked.is_empty() {
-        return Ok(Vec::new());
+    achievements.reported_progress = definitions
+        .iter()
+        .filter(|a| !is_already_unlocked(&a.id, &achievements.unlocked_achievements))
+        .map(|a| (a.id.clone(), achievement_progress(&a.requirement, &stats).current))
+        .collect();
+
+    let unlocked_count = achievements.unlocked_achievements.len() as u32;
+    let progression = compute_progression(&rules, &stats, unlocked_count);
+    let level_up = record_progression(&mut achievements, &progression);
+
+    if achievements != before {
+        save_achievements(&achievements)?;
     }
-    
-    let records = process_unlocked_achievements(&mut achievements, &newly_unlocked);
-    save_achievements(&achievements)?;
-    
-    let result: Vec<(Achievement, String)> = newly_unlocked
+
+    let unlocked: Vec<(Achievement, String)> = newly_unlocked
         .into_iter()
         .zip(records.into_iter())
         .map(|(achievement, record)| (achievement, record.unlocked_at))
         .collect();
-    
-    Ok(result)
+
+    Ok(AchievementCheck {
+        unlocked,
+        progress: deltas,
+        level_up,
+    })
 }
 
 /// Achievement data structure matching AR9 specification
@@ -278,6 +802,56 @@ pub struct AchievementsData {
 
     #[serde(default)]
     pub last_streak_date: Option<String>,
+
+    /// Unspent streak freezes
+    #[serde(default)]
+    pub streak_freezes: u32,
+
+    /// Missed days protected by a streak freeze
+    #[serde(default)]
+    pub frozen_days: Vec<FrozenDay>,
+
+    /// First day (YYYY-MM-DD) of the streak that freezes were last earned for
+    #[serde(default)]
+    pub freeze_run_start: Option<String>,
+
+    /// Freezes already earned for the streak starting at `freeze_run_start`
+    #[serde(default)]
+    pub freezes_earned_in_run: u32,
+
+    /// Day (YYYY-MM-DD) the last streak-at-risk reminder was sent for
+    #[serde(default)]
+    pub last_streak_reminder: Option<String>,
+
+    /// Progress last reported per locked achievement, to compute progress deltas
+    #[serde(default)]
+    pub reported_progress: BTreeMap<String, u32>,
+
+    /// XP as of the last progression update
+    #[serde(default)]
+    pub xp: u32,
+
+    /// Level as of the last progression update (0 = not computed yet)
+    #[serde(default)]
+    pub level: u32,
+}
+
+impl AchievementsData {
+    /// Dates protected by a streak freeze
+    pub fn frozen_dates(&self) -> BTreeSet<NaiveDate> {
+        self.frozen_days
+            .iter()
+            .filter_map(|f| NaiveDate::parse_from_str(&f.date, "%Y-%m-%d").ok())
+            .collect()
+    }
+}
+
+/// A missed day protected by a streak freeze
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FrozenDay {
+    pub date: String,
+    pub frozen_at: String,
 }
 
 impl Default for AchievementsData {
@@ -289,6 +863,14 @@ impl Default for AchievementsData {
             longest_streak: 0,
             total_sessions: 0,
             last_streak_date: None,
+            streak_freezes: 0,
+            frozen_days: Vec::new(),
+            freeze_run_start: None,
+            freezes_earned_in_run: 0,
+            last_streak_reminder: None,
+            reported_progress: BTreeMap::new(),
+            xp: 0,
+            level: 0,
         }
     }
 }
@@ -300,6 +882,7 @@ pub struct StreakDataResponse {
     pub current_streak: u32,
     pub longest_streak: u32,
     pub last_streak_date: Option<String>,
+    pub streak_freezes: u32,
 }
 
 impl From<&AchievementsData> for StreakDataResponse {
@@ -308,16 +891,39 @@ impl From<&AchievementsData> for StreakDataResponse {
             current_streak: data.current_streak,
             longest_streak: data.longest_streak,
             last_streak_date: data.last_streak_date.clone(),
+            streak_freezes: data.streak_freezes,
         }
     }
 }
 
+/// Streak runs response for IPC commands
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreakHistoryResponse {
+    /// Every streak run, oldest first
+    pub runs: Vec<StreakRun>,
+    pub current_streak: u32,
+    pub longest_streak: u32,
+}
+
+/// Streak freeze status for IPC commands
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreakFreezesResponse {
+    pub available: u32,
+    pub max_freezes: u32,
+    /// Streak days left until the next freeze is earned (None = freezes disabled or at the maximum)
+    pub days_until_next_freeze: Option<u32>,
+    pub history: Vec<FrozenDay>,
+}
+
 pub fn get_achievements_file_path() -> Result<PathBuf, AppError> {
     let data_dir = get_data_directory()?;
     Ok(data_dir.join("achievements.json"))
 }
 
 pub fn load_achievements() -> Result<AchievementsData, AppError> {
+    let _lock = lock_storage();
     let achievements_path = get_achievements_file_path()?;
 
     if !achievements_path.exists() {
@@ -370,115 +976,374 @@ fn atomic_write(path: &PathBuf, content: &str) -> Result<(), AppError> {
     Ok(())
 }
 
-fn has_complete_session_on_date(date: NaiveDate) -> Result<bool, AppError> {
-    let sessions_dir = get_sessions_directory()?;
-    let file_path = sessions_dir.join(format!("{}.md", date.format("%Y-%m-%d")));
+/// How a day counts toward the streak
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreakDay {
+    /// The daily minimum was reached
+    Met,
+    Missed,
+    /// A rest day, which neither breaks nor extends the streak
+    Rest,
+    /// A missed day covered by a streak freeze, which neither breaks nor extends the streak
+    Frozen,
+}
 
-    if !file_path.exists() {
-        return Ok(false);
+/// Classify a day under the streak rules. A zero minimum is treated as 1.
+pub fn streak_day(rules: &StreakRules, date: NaiveDate, summary: &DailySummary) -> StreakDay {
+    if rules.is_rest_day(date) {
+        StreakDay::Rest
+    } else if goal_value(rules.daily_minimum.metric, summary) >= rules.daily_minimum.target.max(1) {
+        StreakDay::Met
+    } else {
+        StreakDay::Missed
     }
+}
 
-    let content = fs::read_to_string(&file_path).map_err(|e| {
-        AppError::StorageError(format!("Failed to read session file: {}", e))
-    })?;
+/// A run of consecutive met days, rest and frozen days aside
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreakRun {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub length: u32,
+}
 
-    Ok(content.contains("✓") && content.contains("Complete"))
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Streaks {
+    /// Every run, oldest first, including the current one
+    pub runs: Vec<StreakRun>,
+    pub current: Option<StreakRun>,
+    pub longest: Option<StreakRun>,
 }
 
-/// Calculate streak by scanning session files backwards from today.
-/// Returns (current_streak, longest_streak) tuple.
-/// Only counts days with at least one completed session.
-pub fn calculate_streak() -> Result<(u32, u32), AppError> {
-    let mut current_streak: u32;
-    let mut date = Local::now().date_naive();
-    let today = date;
+impl Streaks {
+    pub fn current_length(&self) -> u32 {
+        self.current.map(|r| r.length).unwrap_or(0)
+    }
+}
 
-    let achievements = load_achievements()?;
-    let mut longest_streak = achievements.longest_streak;
+/// Compute the current and longest streak from day summaries, walking every day
+/// from the first summary to `today`. Today not reaching the minimum yet doesn't
+/// break the current streak.
+pub fn compute_streaks(
+    summaries: &BTreeMap<NaiveDate, DailySummary>,
+    rules: &StreakRules,
+    frozen: &BTreeSet<NaiveDate>,
+    today: NaiveDate,
+) -> Streaks {
+    let Some(first) = summaries.keys().next().copied() else {
+        return Streaks::default();
+    };
+
+    let empty = DailySummary::default();
+    let mut runs: Vec<StreakRun> = Vec::new();
+    let mut run: Option<StreakRun> = None;
+    let mut longest: Option<StreakRun> = None;
+
+    for date in first.iter_days().take_while(|d| *d <= today) {
+        let summary = summaries.get(&date).unwrap_or(&empty);
+        let day = match streak_day(rules, date, summary) {
+            StreakDay::Missed if frozen.contains(&date) => StreakDay::Frozen,
+            day => day,
+        };
+        match day {
+            StreakDay::Rest | StreakDay::Frozen => continue,
+            StreakDay::Missed if date == today => continue,
+            StreakDay::Missed => runs.extend(run.take()),
+            StreakDay::Met => {
+                run = Some(match run {
+                    Some(r) => StreakRun {
+                        end: date,
+                        length: r.length + 1,
+                        ..r
+                    },
+                    None => StreakRun {
+                        start: date,
+                        end: date,
+                        length: 1,
+                    },
+                });
+                if longest.map(|l| l.length) < run.map(|r| r.length) {
+                    longest = run;
+                }
+            }
+        }
+    }
 
-    let today_has_session = has_complete_session_on_date(today)?;
+    runs.extend(run);
 
-    if today_has_session {
-        current_streak = 1;
-        date = match date.pred_opt() {
-            Some(d) => d,
-            None => return Ok((current_streak, longest_streak.max(current_streak))),
-        };
+    Streaks {
+        runs,
+        current: run,
+        longest,
+    }
+}
+
+/// Missed days between the last met day and today that would break the streak,
+/// oldest first. They are only returned if `available` freezes cover all of them,
+/// so freezes are never wasted on a streak that is lost anyway.
+pub fn days_to_freeze(
+    summaries: &BTreeMap<NaiveDate, DailySummary>,
+    rules: &StreakRules,
+    frozen: &BTreeSet<NaiveDate>,
+    today: NaiveDate,
+    available: u32,
+) -> Vec<NaiveDate> {
+    let last_met = summaries
+        .iter()
+        .rev()
+        .find(|(date, summary)| **date < today && streak_day(rules, **date, summary) == StreakDay::Met)
+        .map(|(date, _)| *date);
+    let Some(last_met) = last_met else {
+        return Vec::new();
+    };
+
+    let empty = DailySummary::default();
+    let missed: Vec<NaiveDate> = last_met
+        .iter_days()
+        .skip(1)
+        .take_while(|d| *d < today)
+        .filter(|d| !frozen.contains(d))
+        .filter(|d| {
+            streak_day(rules, *d, summaries.get(d).unwrap_or(&empty)) == StreakDay::Missed
+        })
+        .collect();
+
+    if missed.len() as u32 <= available {
+        missed
     } else {
-        date = match date.pred_opt() {
-            Some(d) => d,
-            None => return Ok((0, longest_streak)),
-        };
+        Vec::new()
+    }
+}
 
-        if !has_complete_session_on_date(date)? {
-            return Ok((0, longest_streak));
-        }
+/// Earn freezes for every `freeze_interval_days` days of the current streak.
+/// Freezes are counted per run, so a streak rescued by a freeze does not earn
+/// the same freezes twice. Without `grant`, only the count is brought up to date.
+fn earn_streak_freezes(
+    achievements: &mut AchievementsData,
+    current: Option<StreakRun>,
+    rules: &StreakRules,
+    grant: bool,
+) {
+    let Some(run) = current else {
+        return;
+    };
+    if rules.freeze_interval_days == 0 {
+        return;
+    }
 
-        current_streak = 1;
-        date = match date.pred_opt() {
-            Some(d) => d,
-            None => return Ok((current_streak, longest_streak.max(current_streak))),
-        };
+    let run_start = run.start.format("%Y-%m-%d").to_string();
+    if achievements.freeze_run_start.as_deref() != Some(run_start.as_str()) {
+        achievements.freeze_run_start = Some(run_start);
+        achievements.freezes_earned_in_run = 0;
     }
 
-    loop {
-        if has_complete_session_on_date(date)? {
-            current_streak += 1;
-            date = match date.pred_opt() {
-                Some(d) => d,
-                None => break,
-            };
-        } else {
-            break;
-        }
+    let earned = run.length / rules.freeze_interval_days;
+    if grant {
+        let new = earned.saturating_sub(achievements.freezes_earned_in_run);
+        achievements.streak_freezes = (achievements.streak_freezes + new).min(rules.max_freezes);
     }
+    achievements.freezes_earned_in_run = achievements.freezes_earned_in_run.max(earned);
+}
 
-    longest_streak = longest_streak.max(current_streak);
+/// Day summaries of the whole history, from the cached day index. With
+/// `verified_only`, only sessions that pass verification are counted.
+fn load_streak_summaries(verified_only: bool) -> Result<BTreeMap<NaiveDate, DailySummary>, AppError> {
+    let dates = list_session_dates()?;
+    let (Some(first), Some(last)) = (dates.first(), dates.last()) else {
+        return Ok(BTreeMap::new());
+    };
+
+    let range = DateRange {
+        start: *first,
+        end: *last,
+    };
+    let summaries = if verified_only {
+        load_verified_daily_summaries(&range)?
+    } else {
+        load_daily_summaries(&range)?
+    };
+    Ok(summaries.into_iter().collect())
+}
+
+/// Calculate streak from the session files under the configured streak rules and
+/// update `achievements` in place. Freezes are spent first on missed days that
+/// would break the current streak, then earned for the resulting streak.
+/// Returns the newly frozen days.
+///
+/// The longest streak never drops below the recorded one, except with
+/// `rules_changed`, where it is recomputed from scratch and no freezes are earned.
+pub fn calculate_streak(
+    achievements: &mut AchievementsData,
+    rules_changed: bool,
+) -> Result<Vec<FrozenDay>, AppError> {
+    let settings = load_settings()?;
+    let rules = &settings.streak;
+    let summaries = load_streak_summaries(settings.count_only_verified_sessions)?;
+    let today = settings.logical_date(Local::now().naive_local());
+
+    let mut frozen = achievements.frozen_dates();
+
+    let to_freeze = days_to_freeze(&summaries, rules, &frozen, today, achievements.streak_freezes);
+    let frozen_at = Utc::now().to_rfc3339();
+    let newly_frozen: Vec<FrozenDay> = to_freeze
+        .iter()
+        .map(|date| FrozenDay {
+            date: date.format("%Y-%m-%d").to_string(),
+            frozen_at: frozen_at.clone(),
+        })
+        .collect();
+    achievements.streak_freezes -= newly_frozen.len() as u32;
+    achievements.frozen_days.extend(newly_frozen.iter().cloned());
+    frozen.extend(to_freeze);
+
+    let streaks = compute_streaks(&summaries, rules, &frozen, today);
+    let longest = streaks.longest.map(|r| r.length).unwrap_or(0);
+    achievements.current_streak = streaks.current_length();
+    achievements.longest_streak = if rules_changed {
+        longest
+    } else {
+        achievements.longest_streak.max(longest)
+    };
+    earn_streak_freezes(achievements, streaks.current, rules, !rules_changed);
 
-    Ok((current_streak, longest_streak))
+    Ok(newly_frozen)
 }
 
 /// Update streak after a COMPLETE session is saved. Interrupted sessions don't count.
-pub fn update_streak_on_completion(session_status: SessionStatus) -> Result<AchievementsData, AppError> {
+/// Returns the updated data and the days frozen along the way.
+pub fn update_streak_on_completion(
+    session_status: SessionStatus,
+) -> Result<(AchievementsData, Vec<FrozenDay>), AppError> {
+    let _lock = lock_storage();
     if session_status != SessionStatus::Complete {
-        return load_achievements();
+        return Ok((load_achievements()?, Vec::new()));
     }
 
     let mut achievements = load_achievements()?;
-    let today = Local::now().date_naive();
-    let today_str = today.format("%Y-%m-%d").to_string();
+    let today_str = logical_today()?.format("%Y-%m-%d").to_string();
 
-    if achievements.last_streak_date.as_ref() == Some(&today_str) {
-        achievements.total_sessions += 1;
-        save_achievements(&achievements)?;
-        return Ok(achievements);
-    }
-
-    let (current_streak, longest_streak) = calculate_streak()?;
+    // Recompute even if the streak was already updated today, since the daily
+    // minimum may only be reached by a later session
+    let newly_frozen = calculate_streak(&mut achievements, false)?;
 
-    achievements.current_streak = current_streak;
-    achievements.longest_streak = longest_streak;
     achievements.last_streak_date = Some(today_str);
     achievements.total_sessions += 1;
 
     save_achievements(&achievements)?;
 
-    Ok(achievements)
+    Ok((achievements, newly_frozen))
+}
+
+/// Recalculate streak to handle missed days, spending freezes where needed.
+/// Returns the updated data and the newly frozen days.
+pub fn refresh_streak() -> Result<(AchievementsData, Vec<FrozenDay>), AppError> {
+    let _lock = lock_storage();
+    let mut achievements = load_achievements()?;
+    let before = achievements.clone();
+
+    let newly_frozen = calculate_streak(&mut achievements, false)?;
+
+    if achievements != before {
+        save_achievements(&achievements)?;
+    }
+
+    Ok((achievements, newly_frozen))
 }
 
 /// Recalculate streak on app startup to handle missed days while app was closed.
 pub fn recalculate_streak_on_startup() -> Result<AchievementsData, AppError> {
+    refresh_streak().map(|(achievements, _)| achievements)
+}
+
+/// Recompute streaks after sessions were added from outside the timer (e.g. an import).
+/// Imported complete focus sessions are added to the lifetime session counter.
+pub fn recalculate_after_import(imported_complete_sessions: u32) -> Result<AchievementsData, AppError> {
+    let _lock = lock_storage();
     let mut achievements = load_achievements()?;
-    
-    let (current_streak, longest_streak) = calculate_streak()?;
-    
+
+    calculate_streak(&mut achievements, false)?;
+
+    achievements.total_sessions += imported_complete_sessions;
+
+    save_achievements(&achievements)?;
+
+    Ok(achievements)
+}
+
+/// Recompute both streaks from scratch after the streak rules changed. The
+/// longest streak may go down under stricter rules.
+pub fn recalculate_streak_for_rules() -> Result<AchievementsData, AppError> {
+    let _lock = lock_storage();
+    let mut achievements = load_achievements()?;
+
+    calculate_streak(&mut achievements, true)?;
+
+    save_achievements(&achievements)?;
+
+    Ok(achievements)
+}
+
+/// Streak runs of the history up to `date`, as they stood at the end of that day
+pub fn streaks_as_of(date: NaiveDate) -> Result<Streaks, AppError> {
+    let settings = load_settings()?;
+    let summaries = load_streak_summaries(settings.count_only_verified_sessions)?;
+    let frozen = load_achievements()?.frozen_dates();
+    Ok(compute_streaks(&summaries, &settings.streak, &frozen, date))
+}
+
+/// Scan the whole history for streak runs and rebuild both streaks from it,
+/// so a reset or imported history recovers the real longest streak
+pub fn get_streak_history() -> Result<StreakHistoryResponse, AppError> {
+    let _lock = lock_storage();
+    let settings = load_settings()?;
+    let summaries = load_streak_summaries(settings.count_only_verified_sessions)?;
+    let mut achievements = load_achievements()?;
+
+    let streaks = compute_streaks(
+        &summaries,
+        &settings.streak,
+        &achievements.frozen_dates(),
+        settings.logical_date(Local::now().naive_local()),
+    );
+    let current_streak = streaks.current_length();
+    let longest_streak = streaks.longest.map(|r| r.length).unwrap_or(0);
+
     if achievements.current_streak != current_streak || achievements.longest_streak != longest_streak {
         achievements.current_streak = current_streak;
         achievements.longest_streak = longest_streak;
         save_achievements(&achievements)?;
     }
 
-    Ok(achievements)
+    Ok(StreakHistoryResponse {
+        runs: streaks.runs,
+        current_streak,
+        longest_streak,
+    })
+}
+
+/// Available streak freezes and the days they were spent on, newest first
+pub fn get_streak_freezes() -> Result<StreakFreezesResponse, AppError> {
+    let achievements = load_achievements()?;
+    let rules = load_settings()?.streak;
+
+    let days_until_next_freeze = if rules.freeze_interval_days == 0
+        || achievements.streak_freezes >= rules.max_freezes
+    {
+        None
+    } else {
+        Some(rules.freeze_interval_days - achievements.current_streak % rules.freeze_interval_days)
+    };
+
+    let mut history = achievements.frozen_days;
+    history.sort_by(|a, b| b.date.cmp(&a.date));
+
+    Ok(StreakFreezesResponse {
+        available: achievements.streak_freezes,
+        max_freezes: rules.max_freezes,
+        days_until_next_freeze,
+        history,
+    })
 }
 
 pub fn get_streak_data() -> Result<StreakDataResponse, AppError> {
@@ -489,6 +1354,9 @@ pub fn get_streak_data() -> Result<StreakDataResponse, AppError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::storage::sessions::test_session;
+    use crate::storage::settings::{Goal, GoalMetric, GoalSettings};
+    use chrono::{DateTime, Weekday};
 
     #[test]
     fn test_default_achievements() {
@@ -514,6 +1382,7 @@ mod tests {
             longest_streak: 14,
             total_sessions: 42,
             last_streak_date: Some("2026-01-29".to_string()),
+            ..Default::default()
         };
 
         let json = serde_json::to_string_pretty(&achievements).unwrap();
@@ -579,6 +1448,17 @@ mod tests {
             longest_streak: 20,
             total_sessions: 100,
             last_streak_date: Some("2026-01-29".to_string()),
+            streak_freezes: 1,
+            frozen_days: vec![FrozenDay {
+                date: "2026-01-20".to_string(),
+                frozen_at: "2026-01-21T08:00:00Z".to_string(),
+            }],
+            freeze_run_start: Some("2026-01-10".to_string()),
+            freezes_earned_in_run: 2,
+            last_streak_reminder: Some("2026-01-28".to_string()),
+            reported_progress: BTreeMap::from([("sessions_500".to_string(), 100)]),
+            xp: 1450,
+            level: 5,
         };
 
         let json = serde_json::to_string(&original).unwrap();
@@ -596,6 +1476,7 @@ mod tests {
             longest_streak: 14,
             total_sessions: 42,
             last_streak_date: Some("2026-01-29".to_string()),
+            ..Default::default()
         };
 
         let response = StreakDataResponse::from(&achievements);
@@ -611,6 +1492,7 @@ mod tests {
             current_streak: 5,
             longest_streak: 10,
             last_streak_date: Some("2026-01-28".to_string()),
+            streak_freezes: 1,
         };
 
         let json = serde_json::to_string(&response).unwrap();
@@ -658,6 +1540,7 @@ mod tests {
             longest_streak: 14,
             total_sessions: 100,
             last_streak_date: Some("2026-01-29".to_string()),
+            ..Default::default()
         };
 
         let response = StreakDataResponse::from(&achievements);
@@ -667,10 +1550,18 @@ mod tests {
         assert_eq!(response.last_streak_date, achievements.last_streak_date);
     }
 
+    fn stats(current_streak: u32, total_sessions: u32) -> AchievementStats {
+        AchievementStats {
+            current_streak,
+            total_sessions,
+            ..Default::default()
+        }
+    }
+
     #[test]
     fn test_check_achievements_first_session_unlocks() {
         let unlocked: Vec<UnlockedAchievement> = vec![];
-        let newly_unlocked = check_achievements(0, 1, &unlocked);
+        let newly_unlocked = check_achievements(&stats(0, 1), &unlocked);
         
         assert_eq!(newly_unlocked.len(), 1);
         assert_eq!(newly_unlocked[0].id, "first_session");
@@ -682,7 +1573,7 @@ mod tests {
             id: "first_session".to_string(),
             unlocked_at: "2026-01-29T10:00:00Z".to_string(),
         }];
-        let newly_unlocked = check_achievements(0, 1, &unlocked);
+        let newly_unlocked = check_achievements(&stats(0, 1), &unlocked);
         
         assert!(newly_unlocked.is_empty());
     }
@@ -690,7 +1581,7 @@ mod tests {
     #[test]
     fn test_check_achievements_streak_based() {
         let unlocked: Vec<UnlockedAchievement> = vec![];
-        let newly_unlocked = check_achievements(7, 10, &unlocked);
+        let newly_unlocked = check_achievements(&stats(7, 10), &unlocked);
         
         let has_week_warrior = newly_unlocked.iter().any(|a| a.id == "streak_7");
         assert!(has_week_warrior);
@@ -699,7 +1590,7 @@ mod tests {
     #[test]
     fn test_check_achievements_session_based() {
         let unlocked: Vec<UnlockedAchievement> = vec![];
-        let newly_unlocked = check_achievements(0, 50, &unlocked);
+        let newly_unlocked = check_achievements(&stats(0, 50), &unlocked);
         
         let has_half_century = newly_unlocked.iter().any(|a| a.id == "sessions_50");
         assert!(has_half_century);
@@ -708,7 +1599,7 @@ mod tests {
     #[test]
     fn test_check_achievements_multiple_unlocks_simultaneously() {
         let unlocked: Vec<UnlockedAchievement> = vec![];
-        let newly_unlocked = check_achievements(7, 50, &unlocked);
+        let newly_unlocked = check_achievements(&stats(7, 50), &unlocked);
         
         let ids: Vec<&str> = newly_unlocked.iter().map(|a| a.id.as_str()).collect();
         
@@ -721,7 +1612,7 @@ mod tests {
     #[test]
     fn test_check_achievements_condition_not_met() {
         let unlocked: Vec<UnlockedAchievement> = vec![];
-        let newly_unlocked = check_achievements(5, 5, &unlocked);
+        let newly_unlocked = check_achievements(&stats(5, 5), &unlocked);
         
         let has_week_warrior = newly_unlocked.iter().any(|a| a.id == "streak_7");
         assert!(!has_week_warrior);
@@ -765,18 +1656,284 @@ mod tests {
     fn test_is_condition_met_streak() {
         let req = AchievementRequirement::Streak(7);
         
-        assert!(is_condition_met(&req, 7, 0));
-        assert!(is_condition_met(&req, 10, 0));
-        assert!(!is_condition_met(&req, 6, 100));
+        assert!(is_condition_met(&req, &stats(7, 0)));
+        assert!(is_condition_met(&req, &stats(10, 0)));
+        assert!(!is_condition_met(&req, &stats(6, 100)));
     }
 
     #[test]
     fn test_is_condition_met_sessions() {
         let req = AchievementRequirement::Sessions(50);
         
-        assert!(is_condition_met(&req, 0, 50));
-        assert!(is_condition_met(&req, 0, 100));
-        assert!(!is_condition_met(&req, 100, 49));
+        assert!(is_condition_met(&req, &stats(0, 50)));
+        assert!(is_condition_met(&req, &stats(0, 100)));
+        assert!(!is_condition_met(&req, &stats(100, 49)));
+    }
+
+    fn focus_session(d: u32, hour: u32, minutes: i64, status: SessionStatus) -> Session {
+        test_session(day(d), (hour, 0), minutes, status, SessionType::Focus)
+    }
+
+    fn history() -> Vec<(NaiveDate, Vec<Session>)> {
+        vec![
+            // 2026-01-05 to 2026-01-07: a three-day streak, then it breaks
+            (day(5), vec![focus_session(5, 6, 25, SessionStatus::Complete)]),
+            (
+                day(6),
+                vec![
+                    focus_session(6, 9, 25, SessionStatus::Complete),
+                    focus_session(6, 10, 25, SessionStatus::Complete),
+                    focus_session(6, 11, 25, SessionStatus::Complete),
+                ],
+            ),
+            (
+                day(7),
+                vec![
+                    focus_session(7, 23, 25, SessionStatus::Complete),
+                    focus_session(7, 14, 10, SessionStatus::Interrupted),
+                ],
+            ),
+            // A two-day comeback streak up to today
+            (day(13), vec![focus_session(13, 1, 25, SessionStatus::Complete)]),
+            (day(14), vec![focus_session(14, 6, 25, SessionStatus::Complete)]),
+        ]
+    }
+
+    fn stats_from_history(
+        days: &[(NaiveDate, Vec<Session>)],
+        achievements: &AchievementsData,
+        settings: &Settings,
+        today: NaiveDate,
+    ) -> AchievementStats {
+        let digests: Vec<(NaiveDate, DayDigest)> = days
+            .iter()
+            .map(|(date, sessions)| (*date, DayDigest::from_sessions(sessions)))
+            .collect();
+        AchievementStats::from_digests(&digests, achievements, settings, today)
+    }
+
+    #[test]
+    fn test_achievement_stats_from_history() {
+        let settings = Settings {
+            goals: GoalSettings {
+                daily: None,
+                weekly: Some(Goal {
+                    metric: GoalMetric::CompletedSessions,
+                    target: 4,
+                }),
+            },
+            ..Default::default()
+        };
+
+        let stats = stats_from_history(
+            &history(),
+            &AchievementsData::default(),
+            &settings,
+            day(14),
+        );
+
+        assert_eq!(stats.total_focus_minutes, 185);
+        assert_eq!(stats.best_day_sessions, 3);
+        assert_eq!(stats.session_starts.len(), 7);
+        assert_eq!(stats.perfect_days, 4);
+        // Only the week of 2026-01-05 is over and has 5 completed sessions
+        assert_eq!(stats.goal_weeks, 1);
+        assert_eq!(stats.comeback_streak, 2);
+        assert_eq!(stats.streak_days, 5);
+    }
+
+    #[test]
+    fn test_verified_only_stats_count_sessions_from_history() {
+        let achievements = AchievementsData {
+            total_sessions: 40,
+            ..Default::default()
+        };
+
+        let stats =
+            stats_from_history(&history(), &achievements, &Settings::default(), day(14));
+        assert_eq!(stats.total_sessions, 40);
+
+        let verified_only = Settings {
+            count_only_verified_sessions: true,
+            ..Default::default()
+        };
+        let stats = stats_from_history(&history(), &achievements, &verified_only, day(14));
+        assert_eq!(stats.total_sessions, 7);
+    }
+
+    #[test]
+    fn test_is_condition_met_richer_requirements() {
+        let stats = stats_from_history(
+            &history(),
+            &AchievementsData::default(),
+            &Settings::default(),
+            day(14),
+        );
+        let time = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+
+        assert!(is_condition_met(&AchievementRequirement::FocusMinutes(185), &stats));
+        assert!(!is_condition_met(&AchievementRequirement::FocusMinutes(186), &stats));
+        assert!(is_condition_met(&AchievementRequirement::SessionsInDay(3), &stats));
+        assert!(!is_condition_met(&AchievementRequirement::SessionsInDay(4), &stats));
+        assert!(is_condition_met(&AchievementRequirement::PerfectDays(4), &stats));
+        assert!(is_condition_met(&AchievementRequirement::Comeback(2), &stats));
+        assert!(!is_condition_met(&AchievementRequirement::Comeback(3), &stats));
+        // No weekly goal configured
+        assert!(!is_condition_met(&AchievementRequirement::GoalWeeks(1), &stats));
+
+        let early_bird = AchievementRequirement::StartedBefore { time: time(7), count: 2 };
+        assert!(is_condition_met(&early_bird, &stats));
+        // The 01:00 session counts as very late, not early, when days start at 04:00
+        let late_day = AchievementStats {
+            day_starts_at: time(4),
+            ..stats.clone()
+        };
+        let early_birds = AchievementRequirement::StartedBefore { time: time(7), count: 3 };
+        assert!(is_condition_met(&early_birds, &stats));
+        assert!(!is_condition_met(&early_birds, &late_day));
+
+        let night_owl = AchievementRequirement::StartedAfter { time: time(22), count: 2 };
+        assert!(!is_condition_met(&night_owl, &stats));
+        assert!(is_condition_met(&night_owl, &late_day));
+    }
+
+    #[test]
+    fn test_achievement_progress() {
+        let progress = achievement_progress(&AchievementRequirement::Sessions(50), &stats(4, 37));
+
+        assert_eq!(progress.current, 37);
+        assert_eq!(progress.target, 50);
+        assert_eq!(progress.percent, 74);
+        assert_eq!(progress.unit, "sessions");
+
+        let capped = achievement_progress(&AchievementRequirement::Streak(7), &stats(9, 0));
+        assert_eq!((capped.current, capped.percent), (7, 100));
+        assert_eq!(capped.unit, "days");
+    }
+
+    #[test]
+    fn test_locked_achievements_include_progress() {
+        let unlocked = vec![UnlockedAchievement {
+            id: "first_session".to_string(),
+            unlocked_at: "2026-01-29T10:00:00Z".to_string(),
+        }];
+
+        let all = achievements_with_status(bundled_achievements(), &stats(4, 37), &unlocked);
+
+        let first = all.iter().find(|a| a.id == "first_session").unwrap();
+        assert!(first.unlocked);
+        assert_eq!(first.progress, None);
+        let half_century = all.iter().find(|a| a.id == "sessions_50").unwrap();
+        assert_eq!(half_century.progress.as_ref().unwrap().current, 37);
+    }
+
+    #[test]
+    fn test_closest_achievements() {
+        let all = achievements_with_status(bundled_achievements(), &stats(4, 37), &[]);
+        let unlocked_count = all.iter().filter(|a| a.unlocked).count();
+        assert_eq!(unlocked_count, 0);
+
+        let closest = closest_achievements(all, 3);
+        let ids: Vec<&str> = closest.iter().map(|a| a.id.as_str()).collect();
+
+        // first_session and sessions_10 are complete but not yet unlocked, then 37 / 50
+        assert_eq!(ids, vec!["first_session", "sessions_10", "sessions_50"]);
+    }
+
+    #[test]
+    fn test_progress_deltas() {
+        let definitions = bundled_achievements();
+        let reported = BTreeMap::from([
+            ("sessions_50".to_string(), 36),
+            ("sessions_100".to_string(), 37),
+        ]);
+
+        let deltas = progress_deltas(&definitions, &stats(4, 37), &[], &reported);
+
+        assert_eq!(
+            deltas,
+            vec![AchievementProgressDelta {
+                id: "sessions_50".to_string(),
+                previous: 36,
+                current: 37,
+                target: 50,
+                unit: "sessions".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_replay_backdates_to_earning_session() {
+        let definitions = bundled_achievements();
+
+        let earned = replay_achievements(
+            &definitions,
+            &history(),
+            &BTreeSet::new(),
+            &Settings::default(),
+        );
+
+        let first_session = history()[0].1[0].end_time.with_timezone(&Utc).to_rfc3339();
+        assert_eq!(earned.len(), 1);
+        assert_eq!(earned[0].0.id, "first_session");
+        assert_eq!(earned[0].1, first_session);
+    }
+
+    #[test]
+    fn test_replay_finds_broken_streak_and_comeback() {
+        let definitions = vec![
+            custom_requirement("streak_3", AchievementRequirement::Streak(3)),
+            custom_requirement("comeback_2", AchievementRequirement::Comeback(2)),
+            custom_requirement("day_3", AchievementRequirement::SessionsInDay(3)),
+            custom_requirement("sessions_99", AchievementRequirement::Sessions(99)),
+        ];
+
+        let earned = replay_achievements(
+            &definitions,
+            &history(),
+            &BTreeSet::new(),
+            &Settings::default(),
+        );
+        let when = |id: &str| {
+            earned
+                .iter()
+                .find(|(a, _)| a.id == id)
+                .map(|(_, at)| DateTime::parse_from_rfc3339(at).unwrap().with_timezone(&Local))
+        };
+
+        // The streak is broken today but was earned on its third day
+        assert_eq!(when("streak_3").unwrap().date_naive(), day(7));
+        assert_eq!(when("comeback_2").unwrap().date_naive(), day(14));
+        // Earned by the third session of 2026-01-06, which ends at 11:25
+        assert_eq!(when("day_3").unwrap().time(), NaiveTime::from_hms_opt(11, 25, 0).unwrap());
+        assert_eq!(when("sessions_99"), None);
+    }
+
+    fn custom_requirement(id: &str, requirement: AchievementRequirement) -> Achievement {
+        Achievement {
+            requirement,
+            ..custom(id, "Star")
+        }
+    }
+
+    #[test]
+    fn test_requirement_serialization() {
+        let early_bird = AchievementRequirement::StartedBefore {
+            time: NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+            count: 5,
+        };
+
+        assert_eq!(
+            serde_json::to_string(&AchievementRequirement::Streak(7)).unwrap(),
+            r#"{"type":"streak","value":7}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&early_bird).unwrap(),
+            r#"{"type":"startedBefore","value":{"time":"07:00:00","count":5}}"#
+        );
+        let parsed: AchievementRequirement =
+            serde_json::from_str(r#"{"type":"sessionsInDay","value":4}"#).unwrap();
+        assert_eq!(parsed, AchievementRequirement::SessionsInDay(4));
     }
 
     #[test]
@@ -795,4 +1952,260 @@ mod tests {
         assert!(ids.contains(&"sessions_100"));
         assert!(ids.contains(&"sessions_500"));
     }
+
+    #[test]
+    fn test_bundled_achievements_are_valid() {
+        let bundled = bundled_achievements();
+
+        assert_eq!(bundled.len(), 8);
+        assert!(validate_achievement_definitions(&bundled, &[]).is_ok());
+    }
+
+    fn custom(id: &str, icon: &str) -> Achievement {
+        Achievement {
+            id: id.to_string(),
+            title: "Team Player".to_string(),
+            description: "Internal badge".to_string(),
+            icon: icon.to_string(),
+            tier: AchievementTier::Gold,
+            requirement: AchievementRequirement::Sessions(20),
+        }
+    }
+
+    #[test]
+    fn test_parse_custom_achievements() {
+        let json = r#"[{
+            "id": "team_20",
+            "title": "Team Player",
+            "description": "Internal badge",
+            "icon": "Star",
+            "tier": "gold",
+            "requirement": { "type": "sessions", "value": 20 }
+        }]"#;
+
+        let parsed = parse_achievement_definitions(json).unwrap();
+
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].requirement, AchievementRequirement::Sessions(20));
+        assert!(validate_achievement_definitions(&parsed, &bundled_achievements()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_duplicate_ids() {
+        let bundled = bundled_achievements();
+
+        let duplicated = vec![custom("team_20", "Star"), custom("team_20", "Star")];
+        assert!(validate_achievement_definitions(&duplicated, &bundled).is_err());
+
+        let shadowing = vec![custom("streak_7", "Star")];
+        assert!(validate_achievement_definitions(&shadowing, &bundled).is_err());
+
+        assert!(validate_achievement_definitions(&[custom(" ", "Star")], &bundled).is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_icon() {
+        let result = validate_achievement_definitions(&[custom("team_20", "Unicorn")], &[]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_unknown_unlocked_ids_are_kept() {
+        let mut achievements = AchievementsData {
+            unlocked: vec!["retired_badge".to_string()],
+            unlocked_achievements: vec![UnlockedAchievement {
+                id: "retired_badge".to_string(),
+                unlocked_at: "2026-01-01T10:00:00Z".to_string(),
+            }],
+            ..Default::default()
+        };
+
+        let newly_unlocked = check_achievements(&stats(0, 1), &achievements.unlocked_achievements);
+        process_unlocked_achievements(&mut achievements, &newly_unlocked);
+
+        assert!(newly_unlocked.iter().any(|a| a.id == "first_session"));
+        assert!(achievements.unlocked.contains(&"retired_badge".to_string()));
+        assert!(achievements
+            .unlocked_achievements
+            .iter()
+            .any(|u| u.id == "retired_badge"));
+    }
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, d).unwrap()
+    }
+
+    fn done(complete_count: u32, total_focus_minutes: u32) -> DailySummary {
+        DailySummary {
+            complete_count,
+            complete_minutes: total_focus_minutes,
+            partial_count: 0,
+            partial_minutes: 0,
+            total_focus_minutes,
+        }
+    }
+
+    #[test]
+    fn test_compute_streaks_default_rules() {
+        let summaries: BTreeMap<NaiveDate, DailySummary> = [
+            (day(1), done(1, 25)),
+            (day(2), done(1, 25)),
+            (day(3), done(1, 25)),
+            (day(5), done(1, 25)),
+            (day(6), done(2, 50)),
+        ]
+        .into_iter()
+        .collect();
+
+        // Today (the 7th) has no session yet, so the current streak is still alive
+        let streaks = compute_streaks(&summaries, &StreakRules::default(), &BTreeSet::new(), day(7));
+
+        assert_eq!(streaks.current_length(), 2);
+        assert_eq!(
+            streaks
+                .runs
+                .iter()
+                .map(|r| (r.start, r.end, r.length))
+                .collect::<Vec<_>>(),
+            vec![(day(1), day(3), 3), (day(5), day(6), 2)]
+        );
+        assert_eq!(
+            streaks.longest,
+            Some(StreakRun {
+                start: day(1),
+                end: day(3),
+                length: 3,
+            })
+        );
+        assert_eq!(
+            compute_streaks(&summaries, &StreakRules::default(), &BTreeSet::new(), day(8)).current_length(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_compute_streaks_daily_minimum() {
+        let rules = StreakRules {
+            daily_minimum: Goal {
+                metric: GoalMetric::FocusMinutes,
+                target: 50,
+            },
+            ..Default::default()
+        };
+        let summaries: BTreeMap<NaiveDate, DailySummary> = [
+            (day(1), done(2, 50)),
+            (day(2), done(1, 25)),
+            (day(3), done(2, 60)),
+        ]
+        .into_iter()
+        .collect();
+
+        let streaks = compute_streaks(&summaries, &rules, &BTreeSet::new(), day(3));
+
+        assert_eq!(streaks.current_length(), 1);
+        assert_eq!(streaks.longest.map(|r| r.length), Some(1));
+    }
+
+    #[test]
+    fn test_compute_streaks_rest_days() {
+        // 2026-01-03 and 2026-01-04 are a Saturday and a Sunday
+        let rules = StreakRules {
+            rest_days: vec![Weekday::Sat, Weekday::Sun],
+            ..Default::default()
+        };
+        let summaries: BTreeMap<NaiveDate, DailySummary> = [
+            (day(1), done(1, 25)),
+            (day(2), done(1, 25)),
+            (day(4), done(3, 75)),
+            (day(5), done(1, 25)),
+        ]
+        .into_iter()
+        .collect();
+
+        let streaks = compute_streaks(&summaries, &rules, &BTreeSet::new(), day(5));
+
+        // The weekend neither breaks nor extends the streak
+        assert_eq!(streaks.current_length(), 3);
+        assert_eq!(streaks.longest.map(|r| (r.start, r.end)), Some((day(1), day(5))));
+    }
+
+    #[test]
+    fn test_streak_day_zero_minimum_needs_a_session() {
+        let rules = StreakRules {
+            daily_minimum: Goal {
+                metric: GoalMetric::CompletedSessions,
+                target: 0,
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(streak_day(&rules, day(5), &DailySummary::default()), StreakDay::Missed);
+        assert_eq!(streak_day(&rules, day(5), &done(1, 25)), StreakDay::Met);
+    }
+
+    #[test]
+    fn test_frozen_days_keep_the_streak() {
+        let summaries: BTreeMap<NaiveDate, DailySummary> = [
+            (day(5), done(1, 25)),
+            (day(6), done(1, 25)),
+            (day(8), done(1, 25)),
+        ]
+        .into_iter()
+        .collect();
+        let frozen: BTreeSet<NaiveDate> = [day(7)].into_iter().collect();
+
+        let streaks = compute_streaks(&summaries, &StreakRules::default(), &frozen, day(8));
+
+        // The frozen day neither breaks nor extends the streak
+        assert_eq!(streaks.current_length(), 3);
+        assert_eq!(streaks.current.unwrap().start, day(5));
+    }
+
+    #[test]
+    fn test_days_to_freeze_covers_whole_gap_or_nothing() {
+        let summaries: BTreeMap<NaiveDate, DailySummary> =
+            [(day(5), done(1, 25)), (day(6), done(1, 25))].into_iter().collect();
+        let rules = StreakRules::default();
+        let none = BTreeSet::new();
+
+        assert!(days_to_freeze(&summaries, &rules, &none, day(7), 2).is_empty());
+        assert_eq!(days_to_freeze(&summaries, &rules, &none, day(8), 2), vec![day(7)]);
+        assert_eq!(
+            days_to_freeze(&summaries, &rules, &none, day(9), 2),
+            vec![day(7), day(8)]
+        );
+        // Three missed days can't be covered by two freezes
+        assert!(days_to_freeze(&summaries, &rules, &none, day(10), 2).is_empty());
+
+        let frozen: BTreeSet<NaiveDate> = [day(7)].into_iter().collect();
+        assert_eq!(days_to_freeze(&summaries, &rules, &frozen, day(9), 1), vec![day(8)]);
+    }
+
+    #[test]
+    fn test_earn_streak_freezes_once_per_run() {
+        let rules = StreakRules::default();
+        let mut achievements = AchievementsData::default();
+        let run = |length| StreakRun {
+            start: day(1),
+            end: day(length),
+            length,
+        };
+
+        earn_streak_freezes(&mut achievements, Some(run(6)), &rules, true);
+        assert_eq!(achievements.streak_freezes, 0);
+
+        earn_streak_freezes(&mut achievements, Some(run(7)), &rules, true);
+        earn_streak_freezes(&mut achievements, Some(run(8)), &rules, true);
+        assert_eq!(achievements.streak_freezes, 1);
+
+        // Spending the freeze and keeping the same run doesn't earn it again
+        achievements.streak_freezes = 0;
+        earn_streak_freezes(&mut achievements, Some(run(9)), &rules, true);
+        assert_eq!(achievements.streak_freezes, 0);
+
+        // Capped at the maximum
+        earn_streak_freezes(&mut achievements, Some(run(28)), &rules, true);
+        assert_eq!(achievements.streak_freezes, 2);
+    }
 }
@@ -0,0 +1,845 @@
+//! Backup and restore module
+//!
+//! Packs settings, achievements and all session files into a single
+//! `.tar.gz` archive with a manifest, and restores such archives by merging
+//! into or replacing the current data. Also runs scheduled automatic backups.
+
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::error::AppError;
+use crate::storage::achievements::{
+    get_achievements_file_path, load_achievements, recalculate_streak_on_startup,
+    save_achievements, AchievementsData,
+};
+use crate::storage::goals::{
+    get_goal_state_file_path, load_goal_state, save_goal_state, GoalState,
+};
+use crate::storage::integrity::get_integrity_key_path;
+use crate::storage::lock::lock_storage;
+use crate::storage::sessions::{
+    atomic_write, get_sessions_directory, list_session_dates, merge_sessions_for_date,
+    DailySessionFile, Session,
+};
+use crate::storage::settings::{
+    get_data_directory, get_settings_file_path, load_settings, save_settings, Settings,
+};
+
+/// Version of the archive layout. Bump when the layout or file formats change.
+pub const BACKUP_SCHEMA_VERSION: u32 = 1;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const SETTINGS_FILE: &str = "settings.json";
+const ACHIEVEMENTS_FILE: &str = "achievements.json";
+const INTEGRITY_KEY_FILE: &str = "integrity.key";
+const GOAL_STATE_FILE: &str = "goals.json";
+const SESSIONS_PREFIX: &str = "sessions/";
+
+/// Snapshot of the current data taken before a replace restore, in the data directory
+const RESTORE_SNAPSHOT_FILE: &str = ".restore-snapshot.tar.gz";
+
+const AUTO_BACKUP_PREFIX: &str = "test-bmad-backup-";
+const AUTO_BACKUP_SUFFIX: &str = ".tar.gz";
+const AUTO_BACKUP_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+/// Manifest stored at the root of every backup archive
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupManifest {
+    pub schema_version: u32,
+    pub app_version: String,
+    pub created_at: String,
+    pub has_settings: bool,
+    pub has_achievements: bool,
+    #[serde(default)]
+    pub has_integrity_key: bool,
+    #[serde(default)]
+    pub has_goal_state: bool,
+    pub session_file_count: u32,
+    pub first_session_date: Option<String>,
+    pub last_session_date: Option<String>,
+}
+
+/// How a restore combines the archive with the current data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RestoreMode {
+    /// Add missing sessions and unlocks, keep current settings
+    Merge,
+    /// Replace settings, achievements and all session files with the archive content
+    Replace,
+}
+
+/// Result of creating a backup
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupResult {
+    pub path: String,
+    pub manifest: BackupManifest,
+}
+
+/// Result of restoring a backup
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreReport {
+    pub mode: RestoreMode,
+    pub manifest: BackupManifest,
+    pub session_files_restored: u32,
+    pub sessions_added: u32,
+    pub current_streak: u32,
+    pub longest_streak: u32,
+}
+
+/// Validated content of a backup archive
+#[derive(Debug)]
+pub struct BackupContents {
+    pub manifest: BackupManifest,
+    pub settings: Option<Settings>,
+    pub achievements: Option<AchievementsData>,
+    pub integrity_key: Option<Vec<u8>>,
+    pub goal_state: Option<GoalState>,
+    pub session_files: Vec<(NaiveDate, String)>,
+}
+
+/// Default file name for a backup created at the given time
+pub fn backup_file_name(now: DateTime<Local>) -> String {
+    format!(
+        "{}{}{}",
+        AUTO_BACKUP_PREFIX,
+        now.format(AUTO_BACKUP_TIMESTAMP_FORMAT),
+        AUTO_BACKUP_SUFFIX
+    )
+}
+
+fn read_optional_file(path: &Path) -> Result<Option<Vec<u8>>, AppError> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    fs::read(path)
+        .map(Some)
+        .map_err(|e| AppError::StorageError(format!("Failed to read {:?}: {}", path, e)))
+}
+
+fn append_file(
+    builder: &mut tar::Builder<GzEncoder<File>>,
+    name: &str,
+    data: &[u8],
+) -> Result<(), AppError> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(data.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(Utc::now().timestamp().max(0) as u64);
+    header.set_cksum();
+    builder
+        .append_data(&mut header, name, data)
+        .map_err(|e| AppError::StorageError(format!("Failed to add {} to backup: {}", name, e)))
+}
+
+/// Write a backup archive from in-memory file contents
+pub fn write_backup_archive(
+    dest: &Path,
+    settings: Option<&[u8]>,
+    achievements: Option<&[u8]>,
+    integrity_key: Option<&[u8]>,
+    goal_state: Option<&[u8]>,
+    session_files: &[(NaiveDate, Vec<u8>)],
+) -> Result<BackupManifest, AppError> {
+    let manifest = BackupManifest {
+        schema_version: BACKUP_SCHEMA_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at: Utc::now().to_rfc3339(),
+        has_settings: settings.is_some(),
+        has_achievements: achievements.is_some(),
+        has_integrity_key: integrity_key.is_some(),
+        has_goal_state: goal_state.is_some(),
+        session_file_count: session_files.len() as u32,
+        first_session_date: session_files
+            .iter()
+            .map(|(date, _)| *date)
+            .min()
+            .map(|d| d.format("%Y-%m-%d").to_string()),
+        last_session_date: session_files
+            .iter()
+            .map(|(date, _)| *date)
+            .max()
+            .map(|d| d.format("%Y-%m-%d").to_string()),
+    };
+
+    let manifest_json = serde_json::to_vec_pretty(&manifest).map_err(|e| {
+        AppError::StorageError(format!("Failed to serialize backup manifest: {}", e))
+    })?;
+
+    let temp_path = dest.with_extension("tmp");
+    let file = File::create(&temp_path).map_err(|e| {
+        AppError::StorageError(format!("Failed to create backup file: {}", e))
+    })?;
+    let mut builder = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+
+    append_file(&mut builder, MANIFEST_FILE, &manifest_json)?;
+    if let Some(data) = settings {
+        append_file(&mut builder, SETTINGS_FILE, data)?;
+    }
+    if let Some(data) = achievements {
+        append_file(&mut builder, ACHIEVEMENTS_FILE, data)?;
+    }
+    if let Some(data) = integrity_key {
+        append_file(&mut builder, INTEGRITY_KEY_FILE, data)?;
+    }
+    if let Some(data) = goal_state {
+        append_file(&mut builder, GOAL_STATE_FILE, data)?;
+    }
+    for (date, data) in session_files {
+        let name = format!("{}{}.md", SESSIONS_PREFIX, date.format("%Y-%m-%d"));
+        append_file(&mut builder, &name, data)?;
+    }
+
+    let encoder = builder
+        .into_inner()
+        .map_err(|e| AppError::StorageError(format!("Failed to finish backup archive: {}", e)))?;
+    let file = encoder
+        .finish()
+        .map_err(|e| AppError::StorageError(format!("Failed to compress backup archive: {}", e)))?;
+    file.sync_all()
+        .map_err(|e| AppError::StorageError(format!("Failed to sync backup file: {}", e)))?;
+
+    fs::rename(&temp_path, dest).map_err(|e| {
+        let _ = fs::remove_file(&temp_path);
+        AppError::StorageError(format!("Failed to rename backup file: {}", e))
+    })?;
+
+    Ok(manifest)
+}
+
+/// Back up settings.json, achievements.json, goals.json and every session file to `dest`.
+/// Session files are read from the effective sessions directory (default or custom `storage_path`).
+///
+/// The integrity key is only added with `include_integrity_key`: anyone holding it can
+/// forge verified sessions, so archives shared or stored elsewhere leave it out.
+pub fn create_backup_archive(
+    dest: &Path,
+    include_integrity_key: bool,
+) -> Result<BackupManifest, AppError> {
+    let settings = read_optional_file(&get_settings_file_path()?)?;
+    let achievements = read_optional_file(&get_achievements_file_path()?)?;
+    let integrity_key = if include_integrity_key {
+        read_optional_file(&get_integrity_key_path()?)?
+    } else {
+        None
+    };
+    let goal_state = read_optional_file(&get_goal_state_file_path()?)?;
+
+    let sessions_dir = get_sessions_directory()?;
+    let mut session_files = Vec::new();
+    for date in list_session_dates()? {
+        let path = sessions_dir.join(format!("{}.md", date.format("%Y-%m-%d")));
+        if let Some(data) = read_optional_file(&path)? {
+            session_files.push((date, data));
+        }
+    }
+
+    write_backup_archive(
+        dest,
+        settings.as_deref(),
+        achievements.as_deref(),
+        integrity_key.as_deref(),
+        goal_state.as_deref(),
+        &session_files,
+    )
+}
+
+/// Read and validate a backup archive without touching the current data
+pub fn read_backup_archive(path: &Path) -> Result<BackupContents, AppError> {
+    let file = File::open(path)
+        .map_err(|e| AppError::StorageError(format!("Failed to open backup file: {}", e)))?;
+    let mut archive = tar::Archive::new(GzDecoder::new(file));
+
+    let mut files: HashMap<String, Vec<u8>> = HashMap::new();
+    let entries = archive
+        .entries()
+        .map_err(|e| AppError::ParseError(format!("Invalid backup archive: {}", e)))?;
+    for entry in entries {
+        let mut entry =
+            entry.map_err(|e| AppError::ParseError(format!("Invalid backup archive: {}", e)))?;
+        let name = entry
+            .path()
+            .map_err(|e| AppError::ParseError(format!("Invalid backup entry: {}", e)))?
+            .to_string_lossy()
+            .to_string();
+        let mut data = Vec::new();
+        entry
+            .read_to_end(&mut data)
+            .map_err(|e| AppError::ParseError(format!("Failed to read {}: {}", name, e)))?;
+        files.insert(name, data);
+    }
+
+    let manifest_data = files
+        .remove(MANIFEST_FILE)
+        .ok_or_else(|| AppError::ParseError("Backup archive has no manifest".to_string()))?;
+    let manifest: BackupManifest = serde_json::from_slice(&manifest_data)
+        .map_err(|e| AppError::ParseError(format!("Invalid backup manifest: {}", e)))?;
+
+    if manifest.schema_version == 0 || manifest.schema_version > BACKUP_SCHEMA_VERSION {
+        return Err(AppError::ParseError(format!(
+            "Unsupported backup schema version {} (this app supports up to {})",
+            manifest.schema_version, BACKUP_SCHEMA_VERSION
+        )));
+    }
+
+    let settings = files
+        .remove(SETTINGS_FILE)
+        .map(|data| {
+            serde_json::from_slice::<Settings>(&data)
+                .map_err(|e| AppError::ParseError(format!("Invalid settings in backup: {}", e)))
+        })
+        .transpose()?;
+
+    let achievements = files
+        .remove(ACHIEVEMENTS_FILE)
+        .map(|data| {
+            serde_json::from_slice::<AchievementsData>(&data).map_err(|e| {
+                AppError::ParseError(format!("Invalid achievements in backup: {}", e))
+            })
+        })
+        .transpose()?;
+
+    let integrity_key = files.remove(INTEGRITY_KEY_FILE);
+
+    let goal_state = files
+        .remove(GOAL_STATE_FILE)
+        .map(|data| {
+            serde_json::from_slice::<GoalState>(&data)
+                .map_err(|e| AppError::ParseError(format!("Invalid goal state in backup: {}", e)))
+        })
+        .transpose()?;
+
+    let mut session_files = Vec::new();
+    for (name, data) in files {
+        let Some(file_name) = name.strip_prefix(SESSIONS_PREFIX) else {
+            continue;
+        };
+        let date = file_name
+            .strip_suffix(".md")
+            .and_then(|stem| NaiveDate::parse_from_str(stem, "%Y-%m-%d").ok())
+            .ok_or_else(|| {
+                AppError::ParseError(format!("Unexpected session file in backup: {}", name))
+            })?;
+        let content = String::from_utf8(data)
+            .map_err(|e| AppError::ParseError(format!("Invalid session file {}: {}", name, e)))?;
+        session_files.push((date, content));
+    }
+    session_files.sort_by_key(|(date, _)| *date);
+
+    if session_files.len() as u32 != manifest.session_file_count
+        || settings.is_some() != manifest.has_settings
+        || achievements.is_some() != manifest.has_achievements
+        || integrity_key.is_some() != manifest.has_integrity_key
+        || goal_state.is_some() != manifest.has_goal_state
+    {
+        return Err(AppError::ParseError(
+            "Backup archive content does not match its manifest".to_string(),
+        ));
+    }
+
+    Ok(BackupContents {
+        manifest,
+        settings,
+        achievements,
+        integrity_key,
+        goal_state,
+        session_files,
+    })
+}
+
+/// Combine two achievement records: union of unlocks (earliest timestamp wins)
+/// and the highest counters. Streaks are recomputed from the session files afterwards.
+pub fn merge_achievements(current: &AchievementsData, incoming: &AchievementsData) -> AchievementsData {
+    let mut merged = current.clone();
+
+    for id in &incoming.unlocked {
+        if !merged.unlocked.contains(id) {
+            merged.unlocked.push(id.clone());
+        }
+    }
+
+    for record in &incoming.unlocked_achievements {
+        match merged
+            .unlocked_achievements
+            .iter_mut()
+            .find(|existing| existing.id == record.id)
+        {
+            Some(existing) => {
+                if record.unlocked_at < existing.unlocked_at {
+                    existing.unlocked_at = record.unlocked_at.clone();
+                }
+            }
+            None => merged.unlocked_achievements.push(record.clone()),
+        }
+    }
+
+    merged.total_sessions = current.total_sessions.max(incoming.total_sessions);
+    merged.longest_streak = current.longest_streak.max(incoming.longest_streak);
+    for frozen in &incoming.frozen_days {
+        if !merged.frozen_days.iter().any(|existing| existing.date == frozen.date) {
+            merged.frozen_days.push(frozen.clone());
+        }
+    }
+    merged
+}
+
+/// Combine two goal states, keeping the latest reached period of each goal
+pub fn merge_goal_states(current: &GoalState, incoming: &GoalState) -> GoalState {
+    GoalState {
+        daily_reached: current.daily_reached.clone().max(incoming.daily_reached.clone()),
+        weekly_reached: current.weekly_reached.clone().max(incoming.weekly_reached.clone()),
+    }
+}
+
+fn remove_session_files() -> Result<(), AppError> {
+    let sessions_dir = get_sessions_directory()?;
+    for date in list_session_dates()? {
+        let path = sessions_dir.join(format!("{}.md", date.format("%Y-%m-%d")));
+        fs::remove_file(&path).map_err(|e| {
+            AppError::StorageError(format!("Failed to remove {:?}: {}", path, e))
+        })?;
+    }
+    Ok(())
+}
+
+/// Drop the chain hashes of sessions merged in from an archive. They were sealed
+/// into another install's chains, which would make them (and the local sessions
+/// chained after them) fail verification here.
+fn unsealed(sessions: Vec<Session>) -> Vec<Session> {
+    sessions
+        .into_iter()
+        .map(|session| Session {
+            chain_hash: None,
+            ..session
+        })
+        .collect()
+}
+
+/// Session file content written back by a replace restore. Without the archived
+/// key, this install's key can't check the archived chain hashes, so the sessions
+/// are restored unverified like merged ones.
+fn restored_session_content(
+    date: NaiveDate,
+    content: &str,
+    key_restored: bool,
+) -> Result<String, AppError> {
+    if key_restored {
+        return Ok(content.to_string());
+    }
+
+    let mut daily_file = DailySessionFile::from_markdown(content, date)?;
+    if daily_file.chain_tip.is_none() && !daily_file.sessions.iter().any(Session::is_verified) {
+        return Ok(content.to_string());
+    }
+    daily_file.sessions = unsealed(daily_file.sessions);
+    daily_file.chain_tip = None;
+    Ok(daily_file.to_markdown())
+}
+
+/// Replace the current settings, achievements and session files with `contents`.
+/// Returns the number of session files and sessions written.
+fn replace_current_data(contents: &BackupContents) -> Result<(u32, u32), AppError> {
+    let sessions_dir = get_sessions_directory()?;
+
+    if let Some(settings) = &contents.settings {
+        // The archived storage location may not exist on this machine
+        let current = load_settings()?;
+        save_settings(&Settings {
+            storage_path: current.storage_path,
+            ..settings.clone()
+        })?;
+    }
+
+    save_achievements(&contents.achievements.clone().unwrap_or_default())?;
+    save_goal_state(&contents.goal_state.clone().unwrap_or_default())?;
+
+    // Keep verified sessions verifiable on this machine
+    if let Some(key) = &contents.integrity_key {
+        fs::write(get_integrity_key_path()?, key).map_err(|e| {
+            AppError::StorageError(format!("Failed to restore integrity key: {}", e))
+        })?;
+    }
+
+    let mut session_files_restored = 0u32;
+    let mut sessions_added = 0u32;
+
+    remove_session_files()?;
+    for (date, content) in &contents.session_files {
+        let file_path = sessions_dir.join(format!("{}.md", date.format("%Y-%m-%d")));
+        let content =
+            restored_session_content(*date, content, contents.integrity_key.is_some())?;
+        atomic_write(&file_path, &content)?;
+        sessions_added += DailySessionFile::from_markdown(&content, *date)?
+            .sessions
+            .len() as u32;
+        session_files_restored += 1;
+    }
+
+    Ok((session_files_restored, sessions_added))
+}
+
+/// Replace the current data, rolling back to a snapshot taken beforehand if any
+/// step fails, so a failed restore never leaves a half-deleted history behind
+fn replace_with_rollback(contents: &BackupContents) -> Result<(u32, u32), AppError> {
+    let snapshot_path = get_data_directory()?.join(RESTORE_SNAPSHOT_FILE);
+    create_backup_archive(&snapshot_path, true)?;
+
+    let error = match replace_current_data(contents) {
+        Ok(counts) => {
+            let _ = fs::remove_file(&snapshot_path);
+            return Ok(counts);
+        }
+        Err(e) => e,
+    };
+
+    let rolled_back = read_backup_archive(&snapshot_path)
+        .and_then(|snapshot| replace_current_data(&snapshot));
+    match rolled_back {
+        Ok(_) => {
+            let _ = fs::remove_file(&snapshot_path);
+            Err(AppError::StorageError(format!(
+                "Restore failed and was rolled back: {}",
+                error
+            )))
+        }
+        Err(rollback_error) => Err(AppError::StorageError(format!(
+            "Restore failed ({}) and rolling back failed ({}); the previous data is kept in {:?}",
+            error, rollback_error, snapshot_path
+        ))),
+    }
+}
+
+/// Restore a validated backup archive
+pub fn restore_backup_archive(path: &Path, mode: RestoreMode) -> Result<RestoreReport, AppError> {
+    let _lock = lock_storage();
+    let contents = read_backup_archive(path)?;
+
+    let mut session_files_restored = 0u32;
+    let mut sessions_added = 0u32;
+
+    match mode {
+        RestoreMode::Replace => {
+            (session_files_restored, sessions_added) = replace_with_rollback(&contents)?;
+        }
+        RestoreMode::Merge => {
+            if let Some(incoming) = &contents.achievements {
+                let merged = merge_achievements(&load_achievements()?, incoming);
+                save_achievements(&merged)?;
+            }
+            if let Some(incoming) = &contents.goal_state {
+                save_goal_state(&merge_goal_states(&load_goal_state()?, incoming))?;
+            }
+
+            for (date, content) in &contents.session_files {
+                let daily_file = DailySessionFile::from_markdown(content, *date)?;
+                let added =
+                    merge_sessions_for_date(*date, unsealed(daily_file.sessions))?.len() as u32;
+                if added > 0 {
+                    session_files_restored += 1;
+                    sessions_added += added;
+                }
+            }
+        }
+    }
+
+    let achievements = recalculate_streak_on_startup()?;
+
+    Ok(RestoreReport {
+        mode,
+        manifest: contents.manifest,
+        session_files_restored,
+        sessions_added,
+        current_streak: achievements.current_streak,
+        longest_streak: achievements.longest_streak,
+    })
+}
+
+/// Parse the creation time encoded in an automatic backup file name
+fn parse_backup_timestamp(file_name: &str) -> Option<DateTime<Local>> {
+    let stamp = file_name
+        .strip_prefix(AUTO_BACKUP_PREFIX)?
+        .strip_suffix(AUTO_BACKUP_SUFFIX)?;
+    let naive = NaiveDateTime::parse_from_str(stamp, AUTO_BACKUP_TIMESTAMP_FORMAT).ok()?;
+    Local.from_local_datetime(&naive).earliest()
+}
+
+/// List automatic backups in a folder, oldest first
+fn list_auto_backups(folder: &Path) -> Result<Vec<(DateTime<Local>, PathBuf)>, AppError> {
+    let entries = fs::read_dir(folder).map_err(|e| {
+        AppError::StorageError(format!("Failed to read backup folder: {}", e))
+    })?;
+
+    let mut backups: Vec<(DateTime<Local>, PathBuf)> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_str()?.to_string();
+            parse_backup_timestamp(&name).map(|stamp| (stamp, entry.path()))
+        })
+        .collect();
+
+    backups.sort_by_key(|(stamp, _)| *stamp);
+    Ok(backups)
+}
+
+/// Whether a new automatic backup is due
+pub fn is_backup_due(last_backup: Option<DateTime<Local>>, now: DateTime<Local>, interval_days: u32) -> bool {
+    match last_backup {
+        Some(last) => now - last >= Duration::days(interval_days.max(1) as i64),
+        None => true,
+    }
+}
+
+/// Create an automatic backup if enabled and due, then prune old ones.
+/// Returns the path of the new backup, if one was created.
+pub fn run_auto_backup_if_due() -> Result<Option<PathBuf>, AppError> {
+    let settings = load_settings()?;
+    let config = &settings.auto_backup;
+
+    let Some(folder) = config.folder.as_ref().filter(|_| config.enabled) else {
+        return Ok(None);
+    };
+
+    let folder = PathBuf::from(folder);
+    fs::create_dir_all(&folder).map_err(|e| {
+        AppError::StorageError(format!("Failed to create backup folder: {}", e))
+    })?;
+
+    let now = Local::now();
+    let existing = list_auto_backups(&folder)?;
+    if !is_backup_due(existing.last().map(|(stamp, _)| *stamp), now, config.interval_days) {
+        return Ok(None);
+    }
+
+    let dest = folder.join(backup_file_name(now));
+    create_backup_archive(&dest, false)?;
+
+    let backups = list_auto_backups(&folder)?;
+    let keep = config.keep_count.max(1) as usize;
+    if backups.len() > keep {
+        for (_, path) in &backups[..backups.len() - keep] {
+            if let Err(e) = fs::remove_file(path) {
+                eprintln!("Warning: Failed to remove old backup {:?}: {}", path, e);
+            }
+        }
+    }
+
+    Ok(Some(dest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::achievements::UnlockedAchievement;
+    use crate::storage::integrity::{append_sealed, verify_history_in};
+    use crate::storage::sessions::{test_session, SessionStatus, SessionType};
+    use tempfile::tempdir;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, day).unwrap()
+    }
+
+    #[test]
+    fn test_backup_archive_round_trip() {
+        let temp = tempdir().unwrap();
+        let dest = temp.path().join("backup.tar.gz");
+
+        let settings = serde_json::to_vec(&Settings::default()).unwrap();
+        let achievements = serde_json::to_vec(&AchievementsData {
+            total_sessions: 12,
+            ..Default::default()
+        })
+        .unwrap();
+        let goal_state = serde_json::to_vec(&GoalState {
+            daily_reached: Some("2026-01-11".to_string()),
+            weekly_reached: None,
+        })
+        .unwrap();
+        let sessions = vec![
+            (date(10), b"# 2026-01-10\n\n## Sessions\n- 09:00 - 09:25 \xE2\x9C\x93 Complete (25m)\n".to_vec()),
+            (date(11), b"# 2026-01-11\n\n## Sessions\n".to_vec()),
+        ];
+
+        let manifest = write_backup_archive(
+            &dest,
+            Some(&settings),
+            Some(&achievements),
+            None,
+            Some(&goal_state),
+            &sessions,
+        )
+        .unwrap();
+        assert_eq!(manifest.session_file_count, 2);
+        assert!(manifest.has_goal_state);
+        assert_eq!(manifest.first_session_date, Some("2026-01-10".to_string()));
+
+        let contents = read_backup_archive(&dest).unwrap();
+        assert_eq!(contents.manifest, manifest);
+        assert_eq!(contents.settings, Some(Settings::default()));
+        assert_eq!(contents.achievements.unwrap().total_sessions, 12);
+        assert_eq!(
+            contents.goal_state.unwrap().daily_reached,
+            Some("2026-01-11".to_string())
+        );
+        assert_eq!(contents.session_files.len(), 2);
+        assert_eq!(contents.session_files[0].0, date(10));
+        assert!(contents.session_files[0].1.contains("Complete (25m)"));
+    }
+
+    #[test]
+    fn test_read_backup_rejects_newer_schema() {
+        let temp = tempdir().unwrap();
+        let dest = temp.path().join("backup.tar.gz");
+
+        let file = File::create(&dest).unwrap();
+        let mut builder = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+        let manifest = serde_json::to_vec(&BackupManifest {
+            schema_version: BACKUP_SCHEMA_VERSION + 1,
+            app_version: "9.9.9".to_string(),
+            created_at: "2026-01-10T10:00:00Z".to_string(),
+            has_settings: false,
+            has_achievements: false,
+            has_integrity_key: false,
+            has_goal_state: false,
+            session_file_count: 0,
+            first_session_date: None,
+            last_session_date: None,
+        })
+        .unwrap();
+        append_file(&mut builder, MANIFEST_FILE, &manifest).unwrap();
+        builder.into_inner().unwrap().finish().unwrap();
+
+        let err = read_backup_archive(&dest).unwrap_err();
+        assert!(err.to_string().contains("Unsupported backup schema version"));
+    }
+
+    #[test]
+    fn test_read_backup_rejects_non_archive() {
+        let temp = tempdir().unwrap();
+        let dest = temp.path().join("not-a-backup.tar.gz");
+        fs::write(&dest, "hello").unwrap();
+
+        assert!(read_backup_archive(&dest).is_err());
+    }
+
+    #[test]
+    fn test_merge_achievements_keeps_earliest_unlock() {
+        let current = AchievementsData {
+            unlocked: vec!["first_session".to_string()],
+            unlocked_achievements: vec![UnlockedAchievement {
+                id: "first_session".to_string(),
+                unlocked_at: "2026-02-01T10:00:00+00:00".to_string(),
+            }],
+            total_sessions: 5,
+            longest_streak: 3,
+            ..Default::default()
+        };
+        let incoming = AchievementsData {
+            unlocked: vec!["first_session".to_string(), "sessions_10".to_string()],
+            unlocked_achievements: vec![
+                UnlockedAchievement {
+                    id: "first_session".to_string(),
+                    unlocked_at: "2026-01-01T10:00:00+00:00".to_string(),
+                },
+                UnlockedAchievement {
+                    id: "sessions_10".to_string(),
+                    unlocked_at: "2026-01-05T10:00:00+00:00".to_string(),
+                },
+            ],
+            total_sessions: 12,
+            longest_streak: 2,
+            ..Default::default()
+        };
+
+        let merged = merge_achievements(&current, &incoming);
+
+        assert_eq!(merged.unlocked.len(), 2);
+        assert_eq!(merged.unlocked_achievements.len(), 2);
+        assert_eq!(
+            merged.unlocked_achievements[0].unlocked_at,
+            "2026-01-01T10:00:00+00:00"
+        );
+        assert_eq!(merged.total_sessions, 12);
+        assert_eq!(merged.longest_streak, 3);
+    }
+
+    #[test]
+    fn test_merge_goal_states_keeps_latest_period() {
+        let current = GoalState {
+            daily_reached: Some("2026-01-12".to_string()),
+            weekly_reached: None,
+        };
+        let incoming = GoalState {
+            daily_reached: Some("2026-01-10".to_string()),
+            weekly_reached: Some("2026-01-05".to_string()),
+        };
+
+        let merged = merge_goal_states(&current, &incoming);
+
+        assert_eq!(merged.daily_reached, Some("2026-01-12".to_string()));
+        assert_eq!(merged.weekly_reached, Some("2026-01-05".to_string()));
+    }
+
+    #[test]
+    fn test_unsealed_drops_chain_hashes() {
+        let content = "# 2026-01-10\n\n## Sessions\n\
+                       - 09:00 - 09:25 ✓ Complete (25m) [verified:0123456789abcdef]\n\
+                       - 10:00 - 10:25 ✓ Complete (25m)\n";
+        let sessions = DailySessionFile::from_markdown(content, date(10)).unwrap().sessions;
+        assert!(sessions[0].is_verified());
+
+        let sessions = unsealed(sessions);
+
+        assert_eq!(sessions.len(), 2);
+        assert!(!sessions.iter().any(Session::is_verified));
+    }
+
+    #[test]
+    fn test_replace_without_key_restores_sessions_unverified() {
+        let mut archived = DailySessionFile::new(date(10));
+        for hour in [9, 10] {
+            append_sealed(
+                b"archived-install-key",
+                &mut archived,
+                test_session(date(10), (hour, 0), 25, SessionStatus::Complete, SessionType::Focus),
+            );
+        }
+
+        let temp = tempdir().unwrap();
+        let content = restored_session_content(date(10), &archived.to_markdown(), false).unwrap();
+        fs::write(temp.path().join("2026-01-10.md"), content).unwrap();
+
+        let report = verify_history_in(b"this-install-key", temp.path()).unwrap();
+
+        assert_eq!(report.unverified_count, 2);
+        assert_eq!(report.tampered_count, 0);
+    }
+
+    #[test]
+    fn test_backup_file_name_round_trip() {
+        let now = Local.with_ymd_and_hms(2026, 1, 10, 8, 30, 15).single().unwrap();
+        let name = backup_file_name(now);
+
+        assert_eq!(name, "test-bmad-backup-20260110-083015.tar.gz");
+        assert_eq!(parse_backup_timestamp(&name), Some(now));
+        assert_eq!(parse_backup_timestamp("notes.tar.gz"), None);
+    }
+
+    #[test]
+    fn test_is_backup_due() {
+        let now = Local.with_ymd_and_hms(2026, 1, 10, 8, 0, 0).single().unwrap();
+
+        assert!(is_backup_due(None, now, 7));
+        assert!(!is_backup_due(Some(now - Duration::days(6)), now, 7));
+        assert!(is_backup_due(Some(now - Duration::days(7)), now, 7));
+    }
+}
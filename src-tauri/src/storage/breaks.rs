@@ -0,0 +1,243 @@
+//! Break analytics module
+//!
+//! Measures how well breaks are taken: minutes taken vs planned, skipped and
+//! shortened breaks, how long it takes to get back to focus after a break, and
+//! the longest run of focus without real rest. A real rest is any time between
+//! two focus sessions (break session or idle time) at least as long as the
+//! configured break duration.
+
+use chrono::{DateTime, Local, NaiveDate};
+use serde::Serialize;
+
+use crate::error::AppError;
+use crate::storage::sessions::{
+    load_sessions_in_range, DateRange, Session, SessionStatus, SessionType,
+};
+use crate::storage::settings::load_settings;
+
+/// Break statistics over one or more days
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakStats {
+    /// One planned break per completed focus session
+    pub planned_break_minutes: u32,
+    pub break_minutes: u32,
+    pub breaks_taken: u32,
+    /// Completed focus sessions not followed by a break before the next focus or the end
+    /// of the day. The last focus session of a closed day counts when no break followed
+    /// it; on the current day its break may still come, so it doesn't count yet.
+    pub skipped_breaks: u32,
+    /// Breaks shorter than the configured duration
+    pub shortened_breaks: u32,
+    /// Break minutes taken as a share of planned minutes, from 0.0 (capped at 1.0)
+    pub compliance_rate: f64,
+    /// Average minutes between the end of a break and the start of the next focus
+    pub average_return_minutes: f64,
+    /// Longest run of focus minutes within a day without a real rest
+    pub longest_focus_stretch_minutes: u32,
+}
+
+fn seconds_between(from: DateTime<Local>, to: DateTime<Local>) -> u32 {
+    (to - from).num_seconds().max(0) as u32
+}
+
+/// Compute break statistics. Sessions of each day are analyzed in start time order,
+/// and focus stretches never span two days. `today` is the current (open) day.
+pub fn analyze_breaks(
+    days: &[(NaiveDate, Vec<Session>)],
+    planned_break_seconds: u32,
+    today: NaiveDate,
+) -> BreakStats {
+    let mut stats = BreakStats::default();
+    let mut break_seconds = 0u32;
+    let mut planned_seconds = 0u32;
+    let mut return_seconds = 0u32;
+    let mut return_count = 0u32;
+    let mut longest_stretch_seconds = 0u32;
+
+    for (date, sessions) in days {
+        let mut sessions: Vec<&Session> = sessions.iter().collect();
+        sessions.sort_by_key(|s| s.start_time);
+
+        let mut last_end: Option<DateTime<Local>> = None;
+        let mut last_break_end: Option<DateTime<Local>> = None;
+        // Whether the previous focus session completed and still awaits its break
+        let mut awaiting_break = false;
+        let mut rest_seconds = 0u32;
+        let mut stretch_seconds = 0u32;
+
+        for session in sessions {
+            let gap = last_end
+                .map(|end| seconds_between(end, session.start_time))
+                .unwrap_or(0);
+            rest_seconds += gap;
+
+            match session.session_type {
+                SessionType::Break => {
+                    stats.breaks_taken += 1;
+                    break_seconds += session.duration_seconds;
+                    rest_seconds += session.duration_seconds;
+                    if session.status == SessionStatus::Interrupted
+                        || session.duration_seconds < planned_break_seconds
+                    {
+                        stats.shortened_breaks += 1;
+                    }
+                    awaiting_break = false;
+                    last_break_end = Some(session.end_time);
+                }
+                SessionType::Focus => {
+                    if awaiting_break {
+                        stats.skipped_breaks += 1;
+                    }
+                    if let Some(break_end) = last_break_end.take() {
+                        return_seconds += seconds_between(break_end, session.start_time);
+                        return_count += 1;
+                    }
+                    if last_end.is_some() && rest_seconds >= planned_break_seconds {
+                        stretch_seconds = 0;
+                    }
+                    stretch_seconds += session.duration_seconds;
+                    longest_stretch_seconds = longest_stretch_seconds.max(stretch_seconds);
+                    rest_seconds = 0;
+
+                    if session.status == SessionStatus::Complete {
+                        planned_seconds += planned_break_seconds;
+                        awaiting_break = true;
+                    } else {
+                        awaiting_break = false;
+                    }
+                }
+            }
+
+            last_end = Some(session.end_time);
+        }
+
+        if awaiting_break && *date < today {
+            stats.skipped_breaks += 1;
+        }
+    }
+
+    stats.planned_break_minutes = planned_seconds / 60;
+    stats.break_minutes = break_seconds / 60;
+    stats.compliance_rate = if planned_seconds > 0 {
+        (break_seconds as f64 / planned_seconds as f64).min(1.0)
+    } else {
+        0.0
+    };
+    stats.average_return_minutes = if return_count > 0 {
+        return_seconds as f64 / return_count as f64 / 60.0
+    } else {
+        0.0
+    };
+    stats.longest_focus_stretch_minutes = longest_stretch_seconds / 60;
+    stats
+}
+
+/// Break statistics of a date range, using the configured break duration as the plan
+pub fn get_break_stats(range: &DateRange) -> Result<BreakStats, AppError> {
+    let settings = load_settings()?;
+    let today = settings.logical_date(Local::now().naive_local());
+    let days = load_sessions_in_range(range)?;
+    Ok(analyze_breaks(&days, settings.break_duration, today))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::sessions::test_session;
+
+    const PLANNED: u32 = 5 * 60;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, 10).unwrap()
+    }
+
+    fn later() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, 20).unwrap()
+    }
+
+    fn focus(start: (u32, u32)) -> Session {
+        test_session(date(), start, 25, SessionStatus::Complete, SessionType::Focus)
+    }
+
+    fn rest(start: (u32, u32), minutes: i64) -> Session {
+        test_session(date(), start, minutes, SessionStatus::Complete, SessionType::Break)
+    }
+
+    #[test]
+    fn test_breaks_taken_and_compliance() {
+        let days = vec![(
+            date(),
+            vec![
+                focus((9, 0)),
+                rest((9, 25), 5),
+                focus((9, 32)),
+                rest((9, 57), 2),
+            ],
+        )];
+
+        let stats = analyze_breaks(&days, PLANNED, later());
+
+        assert_eq!(stats.planned_break_minutes, 10);
+        assert_eq!(stats.break_minutes, 7);
+        assert_eq!(stats.breaks_taken, 2);
+        assert_eq!(stats.shortened_breaks, 1);
+        assert_eq!(stats.skipped_breaks, 0);
+        assert_eq!(stats.compliance_rate, 0.7);
+        assert_eq!(stats.average_return_minutes, 2.0);
+    }
+
+    #[test]
+    fn test_skipped_breaks_extend_the_focus_stretch() {
+        let days = vec![(
+            date(),
+            vec![
+                focus((9, 0)),
+                // Back to back, no rest
+                focus((9, 25)),
+                focus((9, 51)),
+                // 15 minutes idle counts as real rest
+                focus((10, 31)),
+            ],
+        )];
+
+        let stats = analyze_breaks(&days, PLANNED, later());
+
+        assert_eq!(stats.skipped_breaks, 4);
+        assert_eq!(stats.longest_focus_stretch_minutes, 75);
+        assert_eq!(stats.compliance_rate, 0.0);
+    }
+
+    #[test]
+    fn test_last_session_of_closed_day_counts_as_skipped() {
+        let days = vec![(date(), vec![focus((9, 0)), rest((9, 25), 5), focus((9, 30))])];
+
+        let stats = analyze_breaks(&days, PLANNED, later());
+
+        assert_eq!(stats.skipped_breaks, 1);
+    }
+
+    #[test]
+    fn test_last_session_of_current_day_is_not_skipped_yet() {
+        let days = vec![(date(), vec![focus((9, 0)), focus((9, 25))])];
+
+        let stats = analyze_breaks(&days, PLANNED, date());
+
+        // The first break was skipped; the second one may still be taken today
+        assert_eq!(stats.skipped_breaks, 1);
+    }
+
+    #[test]
+    fn test_stretch_does_not_span_days() {
+        let next_day = NaiveDate::from_ymd_opt(2026, 1, 11).unwrap();
+        // Back to back, but filed under two different days
+        let days = vec![
+            (date(), vec![focus((9, 0))]),
+            (next_day, vec![focus((9, 25))]),
+        ];
+
+        let stats = analyze_breaks(&days, PLANNED, later());
+
+        assert_eq!(stats.longest_focus_stretch_minutes, 25);
+    }
+}
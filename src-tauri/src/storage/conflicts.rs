@@ -0,0 +1,234 @@
+//! Sync conflict resolution module
+//!
+//! File sync tools (Syncthing, Dropbox, Nextcloud...) create conflict copies such as
+//! `2026-01-10.sync-conflict-20260110-184500-ABCDEFG.md` when two machines write the
+//! same day file. This module merges their sessions back into the canonical day file
+//! and archives the copies in `sessions/.conflicts/`. Sessions that verify in their
+//! copy are sealed again onto the canonical day's integrity chain.
+
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::AppError;
+use crate::storage::achievements::{recalculate_after_import, AchievementsData};
+use crate::storage::integrity::{load_or_create_integrity_key, merge_sealed_copy};
+use crate::storage::lock::lock_storage;
+use crate::storage::sessions::{
+    atomic_write, get_sessions_directory, load_daily_file, DailySessionFile, SessionStatus,
+    SessionType,
+};
+use crate::storage::settings::load_settings;
+
+/// Sub-directory of the sessions directory where merged conflict copies are kept
+const CONFLICTS_ARCHIVE_DIR: &str = ".conflicts";
+
+/// Result of a conflict resolution pass
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConflictMergeReport {
+    pub conflict_files: u32,
+    pub sessions_added: u32,
+    /// Completed focus sessions among the added ones
+    pub complete_focus_added: u32,
+    /// Completed focus sessions among the added ones that passed verification
+    pub verified_complete_focus_added: u32,
+    pub days_merged: Vec<String>,
+}
+
+/// Return the day a conflict copy belongs to, or `None` if the file is not a conflict copy
+pub fn parse_conflict_file_name(file_name: &str) -> Option<NaiveDate> {
+    let stem = file_name.strip_suffix(".md")?;
+    let date = NaiveDate::parse_from_str(stem.get(..10)?, "%Y-%m-%d").ok()?;
+    let rest = &stem[10..];
+
+    if rest.is_empty() || !rest.to_lowercase().contains("conflict") {
+        return None;
+    }
+
+    Some(date)
+}
+
+/// Move a merged conflict copy into the archive directory, never overwriting an older copy
+fn archive_conflict_file(path: &Path, archive_dir: &Path) -> Result<(), AppError> {
+    fs::create_dir_all(archive_dir).map_err(|e| {
+        AppError::StorageError(format!("Failed to create conflicts archive: {}", e))
+    })?;
+
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| AppError::StorageError(format!("Invalid conflict file {:?}", path)))?;
+    let mut dest = archive_dir.join(file_name);
+    let mut suffix = 1;
+    while dest.exists() {
+        dest = archive_dir.join(format!("{}.{}", file_name.to_string_lossy(), suffix));
+        suffix += 1;
+    }
+
+    fs::rename(path, &dest).map_err(|e| {
+        AppError::StorageError(format!("Failed to archive conflict file {:?}: {}", path, e))
+    })
+}
+
+/// Merge every conflict copy found in `sessions_dir` into its canonical day file,
+/// verifying and sealing with `key`
+pub fn resolve_conflicts_in(
+    sessions_dir: &Path,
+    key: &[u8],
+) -> Result<ConflictMergeReport, AppError> {
+    let entries = fs::read_dir(sessions_dir).map_err(|e| {
+        AppError::StorageError(format!("Failed to read sessions directory: {}", e))
+    })?;
+
+    let mut conflicts: Vec<(NaiveDate, PathBuf)> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| {
+            let date = parse_conflict_file_name(entry.file_name().to_str()?)?;
+            Some((date, entry.path()))
+        })
+        .collect();
+    conflicts.sort();
+
+    let archive_dir = sessions_dir.join(CONFLICTS_ARCHIVE_DIR);
+    let mut report = ConflictMergeReport::default();
+
+    for (date, path) in conflicts {
+        let content = fs::read_to_string(&path).map_err(|e| {
+            AppError::StorageError(format!("Failed to read conflict file {:?}: {}", path, e))
+        })?;
+        let conflict_file = DailySessionFile::from_markdown(&content, date)?;
+
+        let canonical = sessions_dir.join(format!("{}.md", date.format("%Y-%m-%d")));
+        let added = {
+            let _lock = lock_storage();
+            let mut daily_file = load_daily_file(date, &canonical)?;
+            let added = merge_sealed_copy(key, &mut daily_file, conflict_file);
+            if !added.is_empty() {
+                atomic_write(&canonical, &daily_file.to_markdown())?;
+            }
+            added
+        };
+
+        archive_conflict_file(&path, &archive_dir)?;
+
+        report.conflict_files += 1;
+        report.sessions_added += added.len() as u32;
+        for session in added.iter().filter(|s| {
+            s.session_type == SessionType::Focus && s.status == SessionStatus::Complete
+        }) {
+            report.complete_focus_added += 1;
+            if session.is_verified() {
+                report.verified_complete_focus_added += 1;
+            }
+        }
+        let day = date.format("%Y-%m-%d").to_string();
+        if !added.is_empty() && !report.days_merged.contains(&day) {
+            report.days_merged.push(day);
+        }
+    }
+
+    Ok(report)
+}
+
+/// Merge conflict copies in the effective sessions directory
+pub fn resolve_sync_conflicts() -> Result<ConflictMergeReport, AppError> {
+    let sessions_dir = get_sessions_directory()?;
+    let key = load_or_create_integrity_key()?;
+    resolve_conflicts_in(&sessions_dir, &key)
+}
+
+/// Recompute streaks and the lifetime session counter after a merge added sessions,
+/// the same way an import does. With `count_only_verified_sessions`, only the merged
+/// sessions that passed verification are counted.
+pub fn recalculate_after_merge(report: &ConflictMergeReport) -> Result<AchievementsData, AppError> {
+    let counted = if load_settings()?.count_only_verified_sessions {
+        report.verified_complete_focus_added
+    } else {
+        report.complete_focus_added
+    };
+    recalculate_after_import(counted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_parse_conflict_file_name() {
+        let date = NaiveDate::from_ymd_opt(2026, 1, 10).unwrap();
+
+        assert_eq!(
+            parse_conflict_file_name("2026-01-10.sync-conflict-20260110-184500-ABCDEFG.md"),
+            Some(date)
+        );
+        assert_eq!(
+            parse_conflict_file_name("2026-01-10 (Ann's conflicted copy 2026-01-10).md"),
+            Some(date)
+        );
+        assert_eq!(parse_conflict_file_name("2026-01-10.md"), None);
+        assert_eq!(parse_conflict_file_name("2026-01-10.sync-conflict.txt"), None);
+        assert_eq!(parse_conflict_file_name("notes-conflict.md"), None);
+    }
+
+    #[test]
+    fn test_resolve_conflicts_merges_and_archives() {
+        let temp = tempdir().unwrap();
+        let dir = temp.path();
+
+        fs::write(
+            dir.join("2026-01-10.md"),
+            "# 2026-01-10\n\n## Sessions\n- 09:00 - 09:25 ✓ Complete (25m)\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("2026-01-10.sync-conflict-20260110-184500-ABCDEFG.md"),
+            "# 2026-01-10\n\n## Sessions\n\
+             - 09:00 - 09:25 ✓ Complete (25m)\n\
+             - 09:25 - 09:30 ✓ Complete (5m) [break]\n\
+             - 14:00 - 14:10 ○ Interrupted (10m)\n",
+        )
+        .unwrap();
+
+        let report = resolve_conflicts_in(dir, b"test-integrity-key").unwrap();
+
+        assert_eq!(report.conflict_files, 1);
+        assert_eq!(report.sessions_added, 2);
+        assert_eq!(report.complete_focus_added, 0);
+        assert_eq!(report.days_merged, vec!["2026-01-10".to_string()]);
+
+        let date = NaiveDate::from_ymd_opt(2026, 1, 10).unwrap();
+        let merged = fs::read_to_string(dir.join("2026-01-10.md")).unwrap();
+        let merged = DailySessionFile::from_markdown(&merged, date).unwrap();
+        assert_eq!(merged.sessions.len(), 3);
+        assert_eq!(merged.summary.complete_count, 1);
+        assert_eq!(merged.summary.partial_count, 1);
+
+        assert!(!dir
+            .join("2026-01-10.sync-conflict-20260110-184500-ABCDEFG.md")
+            .exists());
+        assert!(dir
+            .join(CONFLICTS_ARCHIVE_DIR)
+            .join("2026-01-10.sync-conflict-20260110-184500-ABCDEFG.md")
+            .exists());
+    }
+
+    #[test]
+    fn test_resolve_conflicts_creates_missing_canonical_file() {
+        let temp = tempdir().unwrap();
+        let dir = temp.path();
+
+        fs::write(
+            dir.join("2026-01-11 (conflicted copy 2026-01-11 101010).md"),
+            "# 2026-01-11\n\n## Sessions\n- 10:00 - 10:25 ✓ Complete (25m)\n",
+        )
+        .unwrap();
+
+        let report = resolve_conflicts_in(dir, b"test-integrity-key").unwrap();
+
+        assert_eq!(report.sessions_added, 1);
+        assert!(dir.join("2026-01-11.md").exists());
+    }
+}
@@ -0,0 +1,281 @@
+//! Session export module
+//!
+//! Serializes recorded sessions to CSV, JSON or iCalendar so they can be used
+//! in spreadsheets, calendars and dashboards.
+
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use crate::error::AppError;
+use crate::storage::sessions::{load_sessions_in_range, DateRange, Session, SessionType};
+
+/// Supported export file formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Ics,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Ics => "ics",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "CSV",
+            ExportFormat::Json => "JSON",
+            ExportFormat::Ics => "iCalendar",
+        }
+    }
+}
+
+/// Result of an export, returned to the frontend
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportResult {
+    pub path: String,
+    pub format: ExportFormat,
+    pub session_count: u32,
+}
+
+const CSV_HEADER: &str = "date,start_time,end_time,duration_seconds,status,session_type";
+
+/// Whether `format` writes `session`: iCalendar leaves breaks out
+fn is_exported(format: ExportFormat, session: &Session) -> bool {
+    format != ExportFormat::Ics || session.session_type == SessionType::Focus
+}
+
+/// Render sessions as CSV, one row per session. The date column is the day the
+/// session is filed under, which follows the configured day boundary.
+pub fn sessions_to_csv(days: &[(NaiveDate, Vec<Session>)]) -> String {
+    let mut content = String::from(CSV_HEADER);
+    content.push('\n');
+
+    for (date, session) in days
+        .iter()
+        .flat_map(|(date, sessions)| sessions.iter().map(move |s| (date, s)))
+    {
+        content.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            date.format("%Y-%m-%d"),
+            session.start_time.to_rfc3339(),
+            session.end_time.to_rfc3339(),
+            session.duration_seconds,
+            session.status.as_str().to_lowercase(),
+            session.session_type.as_str(),
+        ));
+    }
+
+    content
+}
+
+/// Render sessions as a pretty-printed JSON array of `Session` structs
+pub fn sessions_to_json(sessions: &[Session]) -> Result<String, AppError> {
+    serde_json::to_string_pretty(sessions)
+        .map_err(|e| AppError::StorageError(format!("Failed to serialize sessions: {}", e)))
+}
+
+/// Render focus sessions as an iCalendar document (one VEVENT per session)
+///
+/// Break sessions are left out. Lines are CRLF-terminated as required by RFC 5545.
+pub fn sessions_to_ics(sessions: &[Session]) -> String {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//test-bmad//Focus Sessions//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+
+    for session in sessions.iter().filter(|s| is_exported(ExportFormat::Ics, s)) {
+        let start = session.start_time.with_timezone(&Utc);
+        let end = session.end_time.with_timezone(&Utc);
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!(
+            "UID:{}-focus@test-bmad",
+            start.format("%Y%m%dT%H%M%SZ")
+        ));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(format!("DTSTART:{}", start.format("%Y%m%dT%H%M%SZ")));
+        lines.push(format!("DTEND:{}", end.format("%Y%m%dT%H%M%SZ")));
+        lines.push(format!(
+            "SUMMARY:Focus session ({})",
+            session.status.as_str()
+        ));
+        lines.push(format!(
+            "DESCRIPTION:{} {}m",
+            session.status.marker(),
+            session.duration_seconds / 60
+        ));
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut content = lines.join("\r\n");
+    content.push_str("\r\n");
+    content
+}
+
+/// Render the sessions of each day in the requested format
+pub fn render_sessions(
+    days: &[(NaiveDate, Vec<Session>)],
+    format: ExportFormat,
+) -> Result<String, AppError> {
+    let sessions: Vec<Session> = days
+        .iter()
+        .flat_map(|(_, sessions)| sessions.iter().cloned())
+        .collect();
+    match format {
+        ExportFormat::Csv => Ok(sessions_to_csv(days)),
+        ExportFormat::Json => sessions_to_json(&sessions),
+        ExportFormat::Ics => Ok(sessions_to_ics(&sessions)),
+    }
+}
+
+/// Default file name suggested in the save dialog
+pub fn default_export_file_name(range: &DateRange, format: ExportFormat) -> String {
+    format!(
+        "sessions_{}_{}.{}",
+        range.start.format("%Y-%m-%d"),
+        range.end.format("%Y-%m-%d"),
+        format.extension()
+    )
+}
+
+/// Export all sessions within a date range to the given path
+pub fn export_sessions_to_path(
+    range: &DateRange,
+    format: ExportFormat,
+    path: &Path,
+) -> Result<ExportResult, AppError> {
+    let days = load_sessions_in_range(range)?;
+    let content = render_sessions(&days, format)?;
+    let session_count = days
+        .iter()
+        .flat_map(|(_, sessions)| sessions)
+        .filter(|s| is_exported(format, s))
+        .count() as u32;
+
+    fs::write(path, content).map_err(|e| {
+        AppError::StorageError(format!("Failed to write export file: {}", e))
+    })?;
+
+    Ok(ExportResult {
+        path: path.to_string_lossy().to_string(),
+        format,
+        session_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::sessions::{test_session, SessionStatus};
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, day).unwrap()
+    }
+
+    fn create_session(hour: u32, minutes: i64, status: SessionStatus, session_type: SessionType) -> Session {
+        test_session(date(10), (hour, 0), minutes, status, session_type)
+    }
+
+    #[test]
+    fn test_sessions_to_csv() {
+        let days = vec![(
+            date(10),
+            vec![
+                create_session(9, 25, SessionStatus::Complete, SessionType::Focus),
+                create_session(10, 5, SessionStatus::Complete, SessionType::Break),
+            ],
+        )];
+
+        let csv = sessions_to_csv(&days);
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], CSV_HEADER);
+        assert!(lines[1].starts_with("2026-01-10,"));
+        assert!(lines[1].ends_with(",1500,complete,focus"));
+        assert!(lines[2].ends_with(",300,complete,break"));
+    }
+
+    #[test]
+    fn test_csv_date_is_the_day_the_session_is_filed_under() {
+        // A 01:00 session filed under the previous day when days start at 04:00
+        let days = vec![(
+            date(10),
+            vec![test_session(
+                date(11),
+                (1, 0),
+                25,
+                SessionStatus::Complete,
+                SessionType::Focus,
+            )],
+        )];
+
+        let csv = sessions_to_csv(&days);
+
+        assert!(csv.lines().nth(1).unwrap().starts_with("2026-01-10,2026-01-11T01:00:00"));
+    }
+
+    #[test]
+    fn test_sessions_to_json_round_trip() {
+        let sessions = vec![create_session(9, 25, SessionStatus::Interrupted, SessionType::Focus)];
+
+        let json = sessions_to_json(&sessions).unwrap();
+        let parsed: Vec<Session> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].status, SessionStatus::Interrupted);
+        assert!(json.contains("\"durationSeconds\": 1500"));
+    }
+
+    #[test]
+    fn test_sessions_to_ics_only_focus_sessions() {
+        let sessions = vec![
+            create_session(9, 25, SessionStatus::Complete, SessionType::Focus),
+            create_session(10, 5, SessionStatus::Complete, SessionType::Break),
+            create_session(11, 12, SessionStatus::Interrupted, SessionType::Focus),
+        ];
+
+        let ics = sessions_to_ics(&sessions);
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        let exported = sessions.iter().filter(|s| is_exported(ExportFormat::Ics, s));
+        assert_eq!(exported.count(), 2);
+        assert!(ics.contains("SUMMARY:Focus session (Complete)"));
+        assert!(ics.contains("SUMMARY:Focus session (Interrupted)"));
+    }
+
+    #[test]
+    fn test_default_export_file_name() {
+        let range = DateRange {
+            start: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            end: NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+        };
+
+        assert_eq!(
+            default_export_file_name(&range, ExportFormat::Ics),
+            "sessions_2026-01-01_2026-01-31.ics"
+        );
+    }
+
+    #[test]
+    fn test_export_format_deserialization() {
+        let format: ExportFormat = serde_json::from_str("\"csv\"").unwrap();
+        assert_eq!(format, ExportFormat::Csv);
+    }
+}
@@ -0,0 +1,273 @@
+//! Focus score module
+//!
+//! Derives a daily focus score from 0 to 100 and analyzes when interrupted
+//! sessions stop. The score weighs three parts of each day's focus sessions:
+//!
+//! - completion: share of sessions completed (50%)
+//! - achievement: share of the planned focus duration actually done (35%)
+//! - calm: `1 / (1 + pauses per session)`, so fewer pauses score higher (15%)
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::error::AppError;
+use crate::storage::sessions::{
+    load_sessions_in_range, DateRange, Session, SessionStatus, SessionType,
+};
+use crate::storage::settings::load_settings;
+
+const COMPLETION_WEIGHT: f64 = 0.5;
+const ACHIEVEMENT_WEIGHT: f64 = 0.35;
+const CALM_WEIGHT: f64 = 0.15;
+
+/// Width of the interruption histogram buckets, in minutes
+const INTERRUPTION_BUCKET_MINUTES: u32 = 5;
+
+/// Focus score of one day
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyFocusScore {
+    pub date: String,
+    /// Weighted score from 0 to 100
+    pub score: u32,
+    pub session_count: u32,
+    pub completion_rate: f64,
+    /// Share of the planned focus duration achieved, from 0.0 to 1.0
+    pub achievement_rate: f64,
+    pub pause_count: u32,
+}
+
+/// Interrupted sessions stopped within `[from_minute, to_minute)` of their start
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterruptionBucket {
+    pub from_minute: u32,
+    pub to_minute: u32,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterruptionAnalysis {
+    pub interrupted_count: u32,
+    pub buckets: Vec<InterruptionBucket>,
+    /// Bucket holding the most interruptions
+    pub most_common_bucket: Option<InterruptionBucket>,
+    /// Median number of minutes into a session at which it was interrupted
+    pub median_minute: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FocusScoreReport {
+    pub range: DateRange,
+    pub days: Vec<DailyFocusScore>,
+    /// Average score of the days with focus sessions
+    pub average_score: Option<u32>,
+    pub interruptions: InterruptionAnalysis,
+}
+
+/// Score a day's focus sessions. Returns `None` for days without focus sessions.
+pub fn score_day(
+    date: NaiveDate,
+    sessions: &[Session],
+    planned_seconds: u32,
+) -> Option<DailyFocusScore> {
+    let focus: Vec<&Session> = sessions
+        .iter()
+        .filter(|s| s.session_type == SessionType::Focus)
+        .collect();
+    if focus.is_empty() {
+        return None;
+    }
+
+    let session_count = focus.len() as u32;
+    let complete_count = focus
+        .iter()
+        .filter(|s| s.status == SessionStatus::Complete)
+        .count() as u32;
+    let pause_count: u32 = focus.iter().map(|s| s.pauses).sum();
+    // Completed sessions reached the plan even if the recorded minutes were rounded down
+    let achieved: u64 = focus
+        .iter()
+        .map(|s| match s.status {
+            SessionStatus::Complete => planned_seconds,
+            SessionStatus::Interrupted => s.duration_seconds.min(planned_seconds),
+        } as u64)
+        .sum();
+
+    let completion_rate = complete_count as f64 / session_count as f64;
+    let achievement_rate = if planned_seconds > 0 {
+        achieved as f64 / (planned_seconds as u64 * session_count as u64) as f64
+    } else {
+        completion_rate
+    };
+    let calm = 1.0 / (1.0 + pause_count as f64 / session_count as f64);
+
+    let score = (COMPLETION_WEIGHT * completion_rate
+        + ACHIEVEMENT_WEIGHT * achievement_rate
+        + CALM_WEIGHT * calm)
+        * 100.0;
+
+    Some(DailyFocusScore {
+        date: date.format("%Y-%m-%d").to_string(),
+        score: score.round() as u32,
+        session_count,
+        completion_rate,
+        achievement_rate,
+        pause_count,
+    })
+}
+
+/// Histogram of how far into a session interruptions happen
+pub fn analyze_interruptions(
+    days: &[(NaiveDate, Vec<Session>)],
+    planned_seconds: u32,
+) -> InterruptionAnalysis {
+    let mut minutes: Vec<u32> = days
+        .iter()
+        .flat_map(|(_, sessions)| sessions)
+        .filter(|s| s.session_type == SessionType::Focus && s.status == SessionStatus::Interrupted)
+        .map(|s| s.duration_seconds / 60)
+        .collect();
+    minutes.sort_unstable();
+
+    let planned_minutes = (planned_seconds / 60).max(INTERRUPTION_BUCKET_MINUTES);
+    let bucket_count = planned_minutes.div_ceil(INTERRUPTION_BUCKET_MINUTES);
+    let mut buckets: Vec<InterruptionBucket> = (0..bucket_count)
+        .map(|i| InterruptionBucket {
+            from_minute: i * INTERRUPTION_BUCKET_MINUTES,
+            to_minute: (i + 1) * INTERRUPTION_BUCKET_MINUTES,
+            count: 0,
+        })
+        .collect();
+    for minute in &minutes {
+        // Sessions running past the plan before being stopped land in the last bucket
+        let index = ((minute / INTERRUPTION_BUCKET_MINUTES) as usize).min(buckets.len() - 1);
+        buckets[index].count += 1;
+    }
+
+    let most_common_bucket = buckets
+        .iter()
+        .filter(|b| b.count > 0)
+        .max_by_key(|b| (b.count, std::cmp::Reverse(b.from_minute)))
+        .cloned();
+
+    InterruptionAnalysis {
+        interrupted_count: minutes.len() as u32,
+        buckets,
+        most_common_bucket,
+        median_minute: minutes.get(minutes.len() / 2).copied(),
+    }
+}
+
+/// Score every day of loaded history and analyze its interruptions
+pub fn build_focus_score_report(
+    range: &DateRange,
+    days: &[(NaiveDate, Vec<Session>)],
+    planned_seconds: u32,
+) -> FocusScoreReport {
+    let scores: Vec<DailyFocusScore> = days
+        .iter()
+        .filter(|(date, _)| range.contains(*date))
+        .filter_map(|(date, sessions)| score_day(*date, sessions, planned_seconds))
+        .collect();
+
+    let average_score = if scores.is_empty() {
+        None
+    } else {
+        let total: u32 = scores.iter().map(|s| s.score).sum();
+        Some((total as f64 / scores.len() as f64).round() as u32)
+    };
+
+    FocusScoreReport {
+        range: *range,
+        days: scores,
+        average_score,
+        interruptions: analyze_interruptions(days, planned_seconds),
+    }
+}
+
+/// Focus scores and interruption analysis of a date range
+pub fn get_focus_score(range: &DateRange) -> Result<FocusScoreReport, AppError> {
+    let planned_seconds = load_settings()?.focus_duration;
+    let days = load_sessions_in_range(range)?;
+    Ok(build_focus_score_report(range, &days, planned_seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::sessions::test_session;
+
+    const PLANNED: u32 = 25 * 60;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, 10).unwrap()
+    }
+
+    fn session(hour: u32, minutes: i64, status: SessionStatus, pauses: u32) -> Session {
+        let mut session = test_session(date(), (hour, 0), minutes, status, SessionType::Focus);
+        session.pauses = pauses;
+        session
+    }
+
+    #[test]
+    fn test_perfect_day_scores_100() {
+        let sessions = vec![
+            session(9, 25, SessionStatus::Complete, 0),
+            session(10, 25, SessionStatus::Complete, 0),
+        ];
+
+        let score = score_day(date(), &sessions, PLANNED).unwrap();
+
+        assert_eq!(score.score, 100);
+        assert_eq!(score.achievement_rate, 1.0);
+    }
+
+    #[test]
+    fn test_interruptions_and_pauses_lower_the_score() {
+        let sessions = vec![
+            session(9, 25, SessionStatus::Complete, 1),
+            session(10, 10, SessionStatus::Interrupted, 1),
+        ];
+
+        let score = score_day(date(), &sessions, PLANNED).unwrap();
+
+        // 0.5 * 0.5 + 0.35 * 0.7 + 0.15 * 0.5 = 0.57
+        assert_eq!(score.completion_rate, 0.5);
+        assert_eq!(score.achievement_rate, 0.7);
+        assert_eq!(score.pause_count, 2);
+        assert_eq!(score.score, 57);
+    }
+
+    #[test]
+    fn test_day_without_focus_has_no_score() {
+        assert_eq!(score_day(date(), &[], PLANNED), None);
+    }
+
+    #[test]
+    fn test_interruption_histogram() {
+        let days = vec![(
+            date(),
+            vec![
+                session(9, 3, SessionStatus::Interrupted, 0),
+                session(10, 8, SessionStatus::Interrupted, 0),
+                session(11, 9, SessionStatus::Interrupted, 0),
+                session(12, 40, SessionStatus::Interrupted, 0),
+                session(13, 25, SessionStatus::Complete, 0),
+            ],
+        )];
+
+        let analysis = analyze_interruptions(&days, PLANNED);
+
+        assert_eq!(analysis.interrupted_count, 4);
+        assert_eq!(analysis.buckets.len(), 5);
+        assert_eq!(analysis.buckets[0].count, 1);
+        assert_eq!(analysis.buckets[1].count, 2);
+        assert_eq!(analysis.buckets[4].count, 1);
+        let most_common = analysis.most_common_bucket.unwrap();
+        assert_eq!((most_common.from_minute, most_common.to_minute), (5, 10));
+        assert_eq!(analysis.median_minute, Some(9));
+    }
+}
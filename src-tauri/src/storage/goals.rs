@@ -0,0 +1,324 @@
+//! Focus goals module
+//!
+//! Evaluates the daily and weekly goals configured in settings against the
+//! session files, and remembers in `goals.json` which periods already reached
+//! their goal so `GoalReached` only fires once per period.
+
+use chrono::{Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::error::AppError;
+use crate::storage::lock::lock_storage;
+use crate::storage::sessions::{atomic_write, DailySummary, DateRange};
+use crate::storage::settings::{
+    get_data_directory, load_settings, Goal, GoalMetric, GoalSettings, WeekStart,
+};
+use crate::storage::stats::load_daily_summaries;
+
+/// Period a goal applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GoalPeriod {
+    Daily,
+    Weekly,
+}
+
+impl GoalPeriod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalPeriod::Daily => "daily",
+            GoalPeriod::Weekly => "weekly",
+        }
+    }
+
+    /// First day of the period containing `date`
+    pub fn period_start(&self, date: NaiveDate, week_start: WeekStart) -> NaiveDate {
+        match self {
+            GoalPeriod::Daily => date,
+            GoalPeriod::Weekly => week_start.week_start_of(date),
+        }
+    }
+}
+
+/// Progress toward one goal in the current period
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoalProgress {
+    pub period: GoalPeriod,
+    pub period_start: String,
+    pub metric: GoalMetric,
+    pub target: u32,
+    pub current: u32,
+    /// Progress from 0 to 100, capped
+    pub percent: u32,
+    pub reached: bool,
+}
+
+/// Periods whose goal was already reached, persisted in `goals.json`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoalState {
+    /// Date (YYYY-MM-DD) of the last day the daily goal was reached
+    #[serde(default)]
+    pub daily_reached: Option<String>,
+
+    /// First day (YYYY-MM-DD) of the last week the weekly goal was reached
+    #[serde(default)]
+    pub weekly_reached: Option<String>,
+}
+
+impl GoalState {
+    fn reached_mut(&mut self, period: GoalPeriod) -> &mut Option<String> {
+        match period {
+            GoalPeriod::Daily => &mut self.daily_reached,
+            GoalPeriod::Weekly => &mut self.weekly_reached,
+        }
+    }
+}
+
+/// Value of a goal metric for a summary
+pub fn goal_value(metric: GoalMetric, summary: &DailySummary) -> u32 {
+    match metric {
+        GoalMetric::FocusMinutes => summary.total_focus_minutes,
+        GoalMetric::CompletedSessions => summary.complete_count,
+    }
+}
+
+/// Whether a summary meets a goal. A zero target is always met.
+pub fn is_goal_met(goal: &Goal, summary: &DailySummary) -> bool {
+    goal_value(goal.metric, summary) >= goal.target
+}
+
+/// Progress toward `goal` for the period starting at `period_start`
+pub fn evaluate_goal(
+    period: GoalPeriod,
+    goal: &Goal,
+    period_start: NaiveDate,
+    summary: &DailySummary,
+) -> GoalProgress {
+    let current = goal_value(goal.metric, summary);
+    let percent = if goal.target == 0 {
+        100
+    } else {
+        ((current as u64 * 100) / goal.target as u64).min(100) as u32
+    };
+
+    GoalProgress {
+        period,
+        period_start: period_start.format("%Y-%m-%d").to_string(),
+        metric: goal.metric,
+        target: goal.target,
+        current,
+        percent,
+        reached: current >= goal.target,
+    }
+}
+
+/// Progress of every configured goal on `today`, given the day summaries of the current week
+pub fn compute_goal_progress(
+    goals: &GoalSettings,
+    week_start: WeekStart,
+    today: NaiveDate,
+    week_summaries: &[(NaiveDate, DailySummary)],
+) -> Vec<GoalProgress> {
+    let mut progress = Vec::new();
+
+    if let Some(goal) = &goals.daily {
+        let summary = week_summaries
+            .iter()
+            .find(|(date, _)| *date == today)
+            .map(|(_, s)| s.clone())
+            .unwrap_or_default();
+        progress.push(evaluate_goal(GoalPeriod::Daily, goal, today, &summary));
+    }
+
+    if let Some(goal) = &goals.weekly {
+        let period_start = GoalPeriod::Weekly.period_start(today, week_start);
+        let mut summary = DailySummary::default();
+        for (_, day) in week_summaries
+            .iter()
+            .filter(|(date, _)| *date >= period_start && *date <= today)
+        {
+            summary.merge(day);
+        }
+        progress.push(evaluate_goal(
+            GoalPeriod::Weekly,
+            goal,
+            period_start,
+            &summary,
+        ));
+    }
+
+    progress
+}
+
+/// Current progress of the configured goals
+pub fn get_goal_progress() -> Result<Vec<GoalProgress>, AppError> {
+    let settings = load_settings()?;
+    if settings.goals.daily.is_none() && settings.goals.weekly.is_none() {
+        return Ok(Vec::new());
+    }
+
+    let today = settings.logical_date(Local::now().naive_local());
+    let week = DateRange {
+        start: GoalPeriod::Weekly.period_start(today, settings.week_start),
+        end: today,
+    };
+    let summaries = load_daily_summaries(&week)?;
+
+    Ok(compute_goal_progress(
+        &settings.goals,
+        settings.week_start,
+        today,
+        &summaries,
+    ))
+}
+
+/// Record reached goals in `state` and return those that were not reached before in their period
+pub fn mark_newly_reached(state: &mut GoalState, progress: &[GoalProgress]) -> Vec<GoalProgress> {
+    progress
+        .iter()
+        .filter(|p| p.reached)
+        .filter(|p| {
+            let reached = state.reached_mut(p.period);
+            if reached.as_deref() == Some(p.period_start.as_str()) {
+                false
+            } else {
+                *reached = Some(p.period_start.clone());
+                true
+            }
+        })
+        .cloned()
+        .collect()
+}
+
+pub fn get_goal_state_file_path() -> Result<PathBuf, AppError> {
+    let data_dir = get_data_directory()?;
+    Ok(data_dir.join("goals.json"))
+}
+
+pub fn load_goal_state() -> Result<GoalState, AppError> {
+    let path = get_goal_state_file_path()?;
+
+    if !path.exists() {
+        return Ok(GoalState::default());
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| AppError::StorageError(format!("Failed to read goals file: {}", e)))?;
+
+    serde_json::from_str(&content)
+        .map_err(|e| AppError::ParseError(format!("Failed to parse goals file: {}", e)))
+}
+
+pub fn save_goal_state(state: &GoalState) -> Result<(), AppError> {
+    let path = get_goal_state_file_path()?;
+
+    let content = serde_json::to_string_pretty(state)
+        .map_err(|e| AppError::StorageError(format!("Failed to serialize goals: {}", e)))?;
+
+    atomic_write(&path, &content)
+}
+
+/// Return the goals reached for the first time in their period, and remember them
+pub fn check_goals_reached(progress: &[GoalProgress]) -> Result<Vec<GoalProgress>, AppError> {
+    if !progress.iter().any(|p| p.reached) {
+        return Ok(Vec::new());
+    }
+
+    let _lock = lock_storage();
+    let mut state = load_goal_state()?;
+    let newly_reached = mark_newly_reached(&mut state, progress);
+    if !newly_reached.is_empty() {
+        save_goal_state(&state)?;
+    }
+
+    Ok(newly_reached)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, d).unwrap()
+    }
+
+    fn summary(complete_count: u32, total_focus_minutes: u32) -> DailySummary {
+        DailySummary {
+            complete_count,
+            complete_minutes: total_focus_minutes,
+            partial_count: 0,
+            partial_minutes: 0,
+            total_focus_minutes,
+        }
+    }
+
+    fn goals() -> GoalSettings {
+        GoalSettings {
+            daily: Some(Goal {
+                metric: GoalMetric::CompletedSessions,
+                target: 4,
+            }),
+            weekly: Some(Goal {
+                metric: GoalMetric::FocusMinutes,
+                target: 300,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_compute_goal_progress() {
+        // 2026-01-07 is a Wednesday; the 4th belongs to the previous week
+        let summaries = vec![
+            (date(4), summary(8, 200)),
+            (date(5), summary(4, 100)),
+            (date(7), summary(2, 50)),
+        ];
+
+        let progress = compute_goal_progress(&goals(), WeekStart::Monday, date(7), &summaries);
+
+        assert_eq!(progress.len(), 2);
+        assert_eq!(progress[0].period, GoalPeriod::Daily);
+        assert_eq!(progress[0].current, 2);
+        assert_eq!(progress[0].percent, 50);
+        assert!(!progress[0].reached);
+        assert_eq!(progress[1].period_start, "2026-01-05");
+        assert_eq!(progress[1].current, 150);
+        assert!(!progress[1].reached);
+    }
+
+    #[test]
+    fn test_percent_is_capped() {
+        let goal = Goal {
+            metric: GoalMetric::FocusMinutes,
+            target: 60,
+        };
+
+        let progress = evaluate_goal(GoalPeriod::Daily, &goal, date(7), &summary(5, 125));
+
+        assert_eq!(progress.percent, 100);
+        assert!(progress.reached);
+    }
+
+    #[test]
+    fn test_goal_reached_once_per_period() {
+        let mut state = GoalState::default();
+        let reached_on =
+            |d| compute_goal_progress(
+                &goals(),
+                WeekStart::Monday,
+                date(d),
+                &[(date(d), summary(4, 100))],
+            );
+
+        let first = mark_newly_reached(&mut state, &reached_on(7));
+        assert_eq!(first.len(), 1);
+        assert_eq!(state.daily_reached.as_deref(), Some("2026-01-07"));
+
+        assert!(mark_newly_reached(&mut state, &reached_on(7)).is_empty());
+        assert_eq!(mark_newly_reached(&mut state, &reached_on(8)).len(), 1);
+    }
+}
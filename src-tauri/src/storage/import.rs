@@ -0,0 +1,690 @@
+//! Session import module
+//!
+//! Reads session history exported by other timers and time trackers
+//! (generic CSV, Toggl CSV, Timewarrior JSON) and merges it into the daily
+//! session files, skipping records that already exist.
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeDelta, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use crate::error::AppError;
+use crate::storage::sessions::{
+    load_sessions_for_date, merge_sessions_for_date, Session, SessionStatus, SessionType,
+};
+use crate::storage::settings::load_settings;
+
+/// Maximum number of skipped-record messages returned in a report
+const MAX_REPORTED_ISSUES: usize = 50;
+
+/// Longest session accepted from an import, in seconds
+const MAX_SESSION_SECONDS: i64 = 24 * 3600;
+
+/// Column mapping for generic CSV files (values are header names)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CsvColumnMapping {
+    pub start: String,
+    #[serde(default)]
+    pub end: Option<String>,
+    #[serde(default)]
+    pub duration_minutes: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub session_type: Option<String>,
+    #[serde(default)]
+    pub delimiter: Option<char>,
+}
+
+/// Supported import sources
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
+pub enum ImportSource {
+    GenericCsv(CsvColumnMapping),
+    TogglCsv,
+    Timewarrior,
+}
+
+/// A record that could not be turned into a session
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportIssue {
+    pub record: u32,
+    pub reason: String,
+}
+
+/// Outcome of an import (or of a dry-run preview)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub total_records: u32,
+    pub new_sessions: Vec<Session>,
+    pub duplicate_count: u32,
+    pub skipped_count: u32,
+    pub issues: Vec<ImportIssue>,
+    pub days_affected: Vec<String>,
+    pub imported_complete_focus: u32,
+}
+
+/// Sessions parsed from a source file, before de-duplication
+#[derive(Debug, Default)]
+pub struct ParsedImport {
+    pub total_records: u32,
+    pub sessions: Vec<Session>,
+    pub issues: Vec<ImportIssue>,
+}
+
+impl ParsedImport {
+    fn skip(&mut self, record: u32, reason: impl Into<String>) {
+        self.issues.push(ImportIssue {
+            record,
+            reason: reason.into(),
+        });
+    }
+}
+
+/// Split CSV content into rows of fields, honouring double-quoted fields
+fn parse_csv(content: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+        } else if c == '"' {
+            in_quotes = true;
+        } else if c == delimiter {
+            row.push(std::mem::take(&mut field));
+        } else if c == '\n' || c == '\r' {
+            if c == '\r' && chars.peek() == Some(&'\n') {
+                chars.next();
+            }
+            row.push(std::mem::take(&mut field));
+            if row.iter().any(|f| !f.trim().is_empty()) {
+                rows.push(std::mem::take(&mut row));
+            } else {
+                row.clear();
+            }
+        } else {
+            field.push(c);
+        }
+    }
+
+    row.push(field);
+    if row.iter().any(|f| !f.trim().is_empty()) {
+        rows.push(row);
+    }
+
+    rows
+}
+
+/// Parse a date-time in the formats commonly produced by exports.
+/// Values without an offset are interpreted as local time.
+fn parse_datetime(value: &str) -> Option<DateTime<Local>> {
+    let value = value.trim();
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Local));
+    }
+
+    // Timewarrior / iCalendar style UTC stamps
+    if let Ok(naive) = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ") {
+        return Some(Utc.from_utc_datetime(&naive).with_timezone(&Local));
+    }
+
+    const LOCAL_FORMATS: [&str; 4] = [
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+    ];
+    LOCAL_FORMATS.iter().find_map(|format| {
+        NaiveDateTime::parse_from_str(value, format)
+            .ok()
+            .and_then(|naive| Local.from_local_datetime(&naive).earliest())
+    })
+}
+
+/// Map a free-form status value to a session status
+fn parse_status(value: &str) -> Option<SessionStatus> {
+    match value.trim().to_lowercase().as_str() {
+        "" | "complete" | "completed" | "done" | "finished" | "true" | "yes" | "✓" => {
+            Some(SessionStatus::Complete)
+        }
+        "interrupted" | "partial" | "cancelled" | "canceled" | "abandoned" | "stopped"
+        | "false" | "no" | "○" => Some(SessionStatus::Interrupted),
+        _ => None,
+    }
+}
+
+/// Map a free-form type value to a session type
+fn parse_session_type(value: &str) -> Option<SessionType> {
+    match value.trim().to_lowercase().as_str() {
+        "" | "focus" | "work" | "pomodoro" => Some(SessionType::Focus),
+        "break" | "short break" | "long break" | "rest" => Some(SessionType::Break),
+        _ => None,
+    }
+}
+
+/// Parse an `HH:MM:SS` duration into seconds
+fn parse_hms_duration(value: &str) -> Option<u32> {
+    let parts: Vec<&str> = value.trim().split(':').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+    let hours: u32 = parts[0].parse().ok()?;
+    let minutes: u32 = parts[1].parse().ok()?;
+    let seconds: u32 = parts[2].parse().ok()?;
+    hours
+        .checked_mul(3600)?
+        .checked_add(minutes.checked_mul(60)?)?
+        .checked_add(seconds)
+}
+
+/// End of a session lasting `seconds`, or `None` when the duration is out of range
+fn end_after(start: DateTime<Local>, seconds: i64) -> Option<DateTime<Local>> {
+    if !(0..=MAX_SESSION_SECONDS).contains(&seconds) {
+        return None;
+    }
+    start.checked_add_signed(TimeDelta::try_seconds(seconds)?)
+}
+
+/// End of a session lasting `minutes`, rejecting non-finite and out-of-range values
+fn end_after_minutes(start: DateTime<Local>, minutes: f64) -> Option<DateTime<Local>> {
+    let seconds = (minutes * 60.0).round();
+    if !seconds.is_finite() || seconds < 0.0 || seconds > MAX_SESSION_SECONDS as f64 {
+        return None;
+    }
+    end_after(start, seconds as i64)
+}
+
+fn build_session(
+    start: DateTime<Local>,
+    end: DateTime<Local>,
+    status: SessionStatus,
+    session_type: SessionType,
+) -> Result<Session, String> {
+    if end <= start {
+        return Err("end time is not after start time".to_string());
+    }
+    if end - start > TimeDelta::seconds(MAX_SESSION_SECONDS) {
+        return Err("session is longer than 24 hours".to_string());
+    }
+    Ok(Session::new(start, end, status, session_type))
+}
+
+fn column_index(header: &[String], name: &str) -> Result<usize, AppError> {
+    header
+        .iter()
+        .position(|h| h.trim().eq_ignore_ascii_case(name.trim()))
+        .ok_or_else(|| AppError::ParseError(format!("Missing column '{}' in CSV header", name)))
+}
+
+fn optional_column_index(header: &[String], name: &Option<String>) -> Result<Option<usize>, AppError> {
+    name.as_ref().map(|n| column_index(header, n)).transpose()
+}
+
+/// Parse a CSV file using a user-supplied column mapping
+pub fn parse_generic_csv(content: &str, mapping: &CsvColumnMapping) -> Result<ParsedImport, AppError> {
+    let rows = parse_csv(content, mapping.delimiter.unwrap_or(','));
+    let (header, records) = rows
+        .split_first()
+        .ok_or_else(|| AppError::ParseError("CSV file is empty".to_string()))?;
+
+    if mapping.end.is_none() && mapping.duration_minutes.is_none() {
+        return Err(AppError::ParseError(
+            "Column mapping needs either an end or a duration column".to_string(),
+        ));
+    }
+
+    let start_idx = column_index(header, &mapping.start)?;
+    let end_idx = optional_column_index(header, &mapping.end)?;
+    let duration_idx = optional_column_index(header, &mapping.duration_minutes)?;
+    let status_idx = optional_column_index(header, &mapping.status)?;
+    let type_idx = optional_column_index(header, &mapping.session_type)?;
+
+    let mut parsed = ParsedImport::default();
+
+    for (i, record) in records.iter().enumerate() {
+        let record_number = i as u32 + 1;
+        parsed.total_records += 1;
+        let field = |idx: usize| record.get(idx).map(|s| s.as_str()).unwrap_or("");
+
+        let Some(start) = parse_datetime(field(start_idx)) else {
+            parsed.skip(record_number, format!("invalid start time '{}'", field(start_idx)));
+            continue;
+        };
+
+        let end = match (end_idx, duration_idx) {
+            (Some(idx), _) if !field(idx).trim().is_empty() => parse_datetime(field(idx)),
+            (_, Some(idx)) => field(idx)
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .and_then(|minutes| end_after_minutes(start, minutes)),
+            _ => None,
+        };
+        let Some(end) = end else {
+            parsed.skip(record_number, "missing or invalid end time / duration");
+            continue;
+        };
+
+        let status = match status_idx {
+            Some(idx) => match parse_status(field(idx)) {
+                Some(status) => status,
+                None => {
+                    parsed.skip(record_number, format!("unknown status '{}'", field(idx)));
+                    continue;
+                }
+            },
+            None => SessionStatus::Complete,
+        };
+
+        let session_type = match type_idx {
+            Some(idx) => match parse_session_type(field(idx)) {
+                Some(session_type) => session_type,
+                None => {
+                    parsed.skip(record_number, format!("unknown session type '{}'", field(idx)));
+                    continue;
+                }
+            },
+            None => SessionType::Focus,
+        };
+
+        match build_session(start, end, status, session_type) {
+            Ok(session) => parsed.sessions.push(session),
+            Err(reason) => parsed.skip(record_number, reason),
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// Parse a Toggl Track detailed CSV export.
+/// Every time entry becomes a completed focus session.
+pub fn parse_toggl_csv(content: &str) -> Result<ParsedImport, AppError> {
+    let rows = parse_csv(content, ',');
+    let (header, records) = rows
+        .split_first()
+        .ok_or_else(|| AppError::ParseError("CSV file is empty".to_string()))?;
+
+    let start_date_idx = column_index(header, "Start date")?;
+    let start_time_idx = column_index(header, "Start time")?;
+    let end_date_idx = column_index(header, "End date").ok();
+    let end_time_idx = column_index(header, "End time").ok();
+    let duration_idx = column_index(header, "Duration").ok();
+
+    let mut parsed = ParsedImport::default();
+
+    for (i, record) in records.iter().enumerate() {
+        let record_number = i as u32 + 1;
+        parsed.total_records += 1;
+        let field = |idx: usize| record.get(idx).map(|s| s.trim()).unwrap_or("");
+
+        let start_value = format!("{} {}", field(start_date_idx), field(start_time_idx));
+        let Some(start) = parse_datetime(&start_value) else {
+            parsed.skip(record_number, format!("invalid start '{}'", start_value));
+            continue;
+        };
+
+        let end = match (end_date_idx, end_time_idx) {
+            (Some(date_idx), Some(time_idx)) => {
+                parse_datetime(&format!("{} {}", field(date_idx), field(time_idx)))
+            }
+            _ => None,
+        }
+        .or_else(|| {
+            duration_idx
+                .and_then(|idx| parse_hms_duration(field(idx)))
+                .and_then(|seconds| end_after(start, seconds as i64))
+        });
+
+        let Some(end) = end else {
+            parsed.skip(record_number, "missing end time and duration");
+            continue;
+        };
+
+        match build_session(start, end, SessionStatus::Complete, SessionType::Focus) {
+            Ok(session) => parsed.sessions.push(session),
+            Err(reason) => parsed.skip(record_number, reason),
+        }
+    }
+
+    Ok(parsed)
+}
+
+#[derive(Debug, Deserialize)]
+struct TimewarriorInterval {
+    start: String,
+    #[serde(default)]
+    end: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// Parse `timew export` JSON output.
+/// Open intervals are skipped; intervals tagged `break` become break sessions.
+pub fn parse_timewarrior_json(content: &str) -> Result<ParsedImport, AppError> {
+    let intervals: Vec<TimewarriorInterval> = serde_json::from_str(content).map_err(|e| {
+        AppError::ParseError(format!("Failed to parse Timewarrior export: {}", e))
+    })?;
+
+    let mut parsed = ParsedImport::default();
+
+    for (i, interval) in intervals.iter().enumerate() {
+        let record_number = i as u32 + 1;
+        parsed.total_records += 1;
+
+        let Some(start) = parse_datetime(&interval.start) else {
+            parsed.skip(record_number, format!("invalid start '{}'", interval.start));
+            continue;
+        };
+        let Some(end) = interval.end.as_deref().and_then(parse_datetime) else {
+            parsed.skip(record_number, "interval is still open");
+            continue;
+        };
+
+        let session_type = if interval.tags.iter().any(|t| t.eq_ignore_ascii_case("break")) {
+            SessionType::Break
+        } else {
+            SessionType::Focus
+        };
+
+        match build_session(start, end, SessionStatus::Complete, session_type) {
+            Ok(session) => parsed.sessions.push(session),
+            Err(reason) => parsed.skip(record_number, reason),
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// Parse file content according to the chosen source
+pub fn parse_import(content: &str, source: &ImportSource) -> Result<ParsedImport, AppError> {
+    match source {
+        ImportSource::GenericCsv(mapping) => parse_generic_csv(content, mapping),
+        ImportSource::TogglCsv => parse_toggl_csv(content),
+        ImportSource::Timewarrior => parse_timewarrior_json(content),
+    }
+}
+
+/// Group sessions by the day file they belong to
+fn group_by_day(sessions: Vec<Session>) -> Result<BTreeMap<NaiveDate, Vec<Session>>, AppError> {
+    let settings = load_settings()?;
+    let mut by_day: BTreeMap<NaiveDate, Vec<Session>> = BTreeMap::new();
+    for session in sessions {
+        by_day
+            .entry(settings.logical_date(session.start_time.naive_local()))
+            .or_default()
+            .push(session);
+    }
+    Ok(by_day)
+}
+
+/// Drop the sessions already present on disk or repeated in the import, without
+/// writing anything. Returns the new sessions per day and the number of duplicates.
+fn dedupe_against_existing(
+    mut by_day: BTreeMap<NaiveDate, Vec<Session>>,
+) -> Result<(BTreeMap<NaiveDate, Vec<Session>>, u32), AppError> {
+    let mut duplicates = 0u32;
+    for (date, day_sessions) in by_day.iter_mut() {
+        let mut seen: HashSet<_> = load_sessions_for_date(*date)?
+            .iter()
+            .map(Session::identity)
+            .collect();
+
+        day_sessions.retain(|session| {
+            let is_new = seen.insert(session.identity());
+            if !is_new {
+                duplicates += 1;
+            }
+            is_new
+        });
+    }
+    by_day.retain(|_, day_sessions| !day_sessions.is_empty());
+
+    Ok((by_day, duplicates))
+}
+
+/// Merge the sessions into their day files. Duplicates are dropped under the storage
+/// lock, so sessions saved meanwhile by the timer or a conflict merge aren't imported
+/// twice. Returns the sessions added per day and the number of duplicates.
+fn merge_into_days(
+    by_day: BTreeMap<NaiveDate, Vec<Session>>,
+) -> Result<(BTreeMap<NaiveDate, Vec<Session>>, u32), AppError> {
+    let mut added_by_day = BTreeMap::new();
+    let mut duplicates = 0u32;
+    for (date, day_sessions) in by_day {
+        let count = day_sessions.len() as u32;
+        let added = merge_sessions_for_date(date, day_sessions)?;
+        duplicates += count - added.len() as u32;
+        if !added.is_empty() {
+            added_by_day.insert(date, added);
+        }
+    }
+
+    Ok((added_by_day, duplicates))
+}
+
+/// Import sessions from a file. With `dry_run` nothing is written and the
+/// report describes what would be imported.
+pub fn import_sessions_from_path(
+    path: &Path,
+    source: &ImportSource,
+    dry_run: bool,
+) -> Result<ImportReport, AppError> {
+    let content = fs::read_to_string(path).map_err(|e| {
+        AppError::StorageError(format!("Failed to read import file: {}", e))
+    })?;
+
+    let parsed = parse_import(&content, source)?;
+    let skipped_count = parsed.issues.len() as u32;
+    let by_day = group_by_day(parsed.sessions)?;
+    let (by_day, duplicate_count) = if dry_run {
+        dedupe_against_existing(by_day)?
+    } else {
+        merge_into_days(by_day)?
+    };
+
+    let days_affected = by_day
+        .keys()
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .collect();
+    let new_sessions: Vec<Session> = by_day.into_values().flatten().collect();
+    let imported_complete_focus = new_sessions
+        .iter()
+        .filter(|s| s.session_type == SessionType::Focus && s.status == SessionStatus::Complete)
+        .count() as u32;
+
+    let mut issues = parsed.issues;
+    issues.truncate(MAX_REPORTED_ISSUES);
+
+    Ok(ImportReport {
+        dry_run,
+        total_records: parsed.total_records,
+        new_sessions,
+        duplicate_count,
+        skipped_count,
+        issues,
+        days_affected,
+        imported_complete_focus,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Timelike;
+
+    #[test]
+    fn test_parse_csv_handles_quotes_and_crlf() {
+        let rows = parse_csv("a,b,c\r\n\"x, y\",\"say \"\"hi\"\"\",3\r\n\r\n", ',');
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1], vec!["x, y", "say \"hi\"", "3"]);
+    }
+
+    #[test]
+    fn test_parse_generic_csv_with_mapping() {
+        let content = "Started;Minutes;Result;Kind\n\
+            2026-01-10 09:00;25;done;focus\n\
+            2026-01-10 09:25;5;done;break\n\
+            2026-01-10 10:00;12;cancelled;focus\n\
+            not a date;25;done;focus\n";
+        let mapping = CsvColumnMapping {
+            start: "Started".to_string(),
+            end: None,
+            duration_minutes: Some("Minutes".to_string()),
+            status: Some("Result".to_string()),
+            session_type: Some("Kind".to_string()),
+            delimiter: Some(';'),
+        };
+
+        let parsed = parse_generic_csv(content, &mapping).unwrap();
+
+        assert_eq!(parsed.total_records, 4);
+        assert_eq!(parsed.sessions.len(), 3);
+        assert_eq!(parsed.issues.len(), 1);
+        assert_eq!(parsed.issues[0].record, 4);
+        assert_eq!(parsed.sessions[0].duration_seconds, 25 * 60);
+        assert_eq!(parsed.sessions[1].session_type, SessionType::Break);
+        assert_eq!(parsed.sessions[2].status, SessionStatus::Interrupted);
+    }
+
+    #[test]
+    fn test_parse_generic_csv_skips_out_of_range_durations() {
+        let content = "start,minutes\n\
+            2026-01-10 09:00,inf\n\
+            2026-01-10 09:00,NaN\n\
+            2026-01-10 09:00,1e12\n\
+            2026-01-10 09:00,1e20\n\
+            2026-01-10 09:00,-25\n\
+            2026-01-10 09:00,1441\n\
+            2026-01-10 09:00,1440\n";
+        let mapping = CsvColumnMapping {
+            start: "start".to_string(),
+            end: None,
+            duration_minutes: Some("minutes".to_string()),
+            status: None,
+            session_type: None,
+            delimiter: None,
+        };
+
+        let parsed = parse_generic_csv(content, &mapping).unwrap();
+
+        assert_eq!(parsed.total_records, 7);
+        assert_eq!(parsed.issues.len(), 6);
+        assert_eq!(parsed.sessions.len(), 1);
+        assert_eq!(parsed.sessions[0].duration_seconds, 24 * 3600);
+    }
+
+    #[test]
+    fn test_parse_generic_csv_requires_end_or_duration() {
+        let mapping = CsvColumnMapping {
+            start: "start".to_string(),
+            end: None,
+            duration_minutes: None,
+            status: None,
+            session_type: None,
+            delimiter: None,
+        };
+
+        assert!(parse_generic_csv("start\n2026-01-10 09:00\n", &mapping).is_err());
+    }
+
+    #[test]
+    fn test_parse_toggl_csv() {
+        let content = "User,Email,Client,Project,Task,Description,Billable,Start date,Start time,End date,End time,Duration,Tags\n\
+            Ann,ann@example.com,,Deep work,,\"Write spec, part 1\",No,2026-01-10,09:00:00,2026-01-10,09:50:00,00:50:00,\n";
+
+        let parsed = parse_toggl_csv(content).unwrap();
+
+        assert_eq!(parsed.sessions.len(), 1);
+        assert_eq!(parsed.sessions[0].duration_seconds, 50 * 60);
+        assert_eq!(parsed.sessions[0].start_time.hour(), 9);
+        assert_eq!(parsed.sessions[0].status, SessionStatus::Complete);
+    }
+
+    #[test]
+    fn test_parse_toggl_csv_rejects_overlong_durations() {
+        assert_eq!(parse_hms_duration("01:30:15"), Some(5415));
+        assert_eq!(parse_hms_duration("4294967295:00:00"), None);
+
+        let content = "Start date,Start time,Duration\n\
+            2026-01-10,09:00:00,4294967295:00:00\n\
+            2026-01-10,09:00:00,25:00:00\n\
+            2026-01-10,09:00:00,00:25:00\n";
+
+        let parsed = parse_toggl_csv(content).unwrap();
+
+        assert_eq!(parsed.issues.len(), 2);
+        assert_eq!(parsed.sessions.len(), 1);
+    }
+
+    #[test]
+    fn test_build_session_rejects_sessions_over_a_day() {
+        let start = Local.with_ymd_and_hms(2026, 1, 10, 9, 0, 0).single().unwrap();
+        let end = start + TimeDelta::hours(25);
+
+        assert!(build_session(start, end, SessionStatus::Complete, SessionType::Focus).is_err());
+    }
+
+    #[test]
+    fn test_parse_timewarrior_json() {
+        let content = r#"[
+            {"id": 3, "start": "20260110T080000Z", "end": "20260110T082500Z", "tags": ["writing"]},
+            {"id": 2, "start": "20260110T082500Z", "end": "20260110T083000Z", "tags": ["break"]},
+            {"id": 1, "start": "20260110T090000Z"}
+        ]"#;
+
+        let parsed = parse_timewarrior_json(content).unwrap();
+
+        assert_eq!(parsed.total_records, 3);
+        assert_eq!(parsed.sessions.len(), 2);
+        assert_eq!(parsed.sessions[0].duration_seconds, 25 * 60);
+        assert_eq!(parsed.sessions[1].session_type, SessionType::Break);
+        assert_eq!(parsed.issues.len(), 1);
+    }
+
+    #[test]
+    fn test_import_source_deserialization() {
+        let source: ImportSource = serde_json::from_str(r#"{"type": "togglCsv"}"#).unwrap();
+        assert_eq!(source, ImportSource::TogglCsv);
+
+        let source: ImportSource = serde_json::from_str(
+            r#"{"type": "genericCsv", "value": {"start": "from", "end": "to"}}"#,
+        )
+        .unwrap();
+        match source {
+            ImportSource::GenericCsv(mapping) => {
+                assert_eq!(mapping.start, "from");
+                assert_eq!(mapping.end, Some("to".to_string()));
+            }
+            _ => panic!("expected generic CSV source"),
+        }
+    }
+
+    #[test]
+    fn test_parse_status_values() {
+        assert_eq!(parse_status("Completed"), Some(SessionStatus::Complete));
+        assert_eq!(parse_status(""), Some(SessionStatus::Complete));
+        assert_eq!(parse_status("abandoned"), Some(SessionStatus::Interrupted));
+        assert_eq!(parse_status("maybe"), None);
+    }
+}
@@ -0,0 +1,555 @@
+//! Session integrity module
+//!
+//! Sessions recorded by the backend timer are sealed with an HMAC-SHA256 hash that
+//! chains them to an earlier verified session of the same day. The key is created
+//! on first use and kept in the storage folder next to `sessions/`, so every machine
+//! syncing that folder seals and verifies with the same key.
+
+use chrono::NaiveDate;
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use sha2::Sha256;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::AppError;
+use crate::storage::sessions::{
+    get_sessions_directory, list_session_dates_in, load_daily_file, DailySessionFile, Session,
+    SessionType,
+};
+use crate::storage::settings::{get_data_directory, load_settings};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// File name of the integrity key, at the root of the storage folder
+pub const INTEGRITY_KEY_FILE: &str = "integrity.key";
+
+/// Number of hex characters of the HMAC kept in the session files
+const CHAIN_HASH_LENGTH: usize = 16;
+
+/// Verification result for a single session entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EntryStatus {
+    /// Recorded by the timer and unchanged since
+    Verified,
+    /// Written without a chain hash (frontend, import, manual edit)
+    Unverified,
+    /// Carries a chain hash that does not match its content or position
+    Tampered,
+}
+
+/// A session entry that failed verification
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityIssue {
+    pub date: String,
+    pub line: String,
+    pub reason: String,
+}
+
+/// Result of `verify_history`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryVerificationReport {
+    pub days_checked: u32,
+    pub verified_count: u32,
+    pub unverified_count: u32,
+    pub tampered_count: u32,
+    pub issues: Vec<IntegrityIssue>,
+}
+
+/// Integrity key location: the custom storage path when set, the data directory otherwise
+pub fn get_integrity_key_path() -> Result<PathBuf, AppError> {
+    let root = match load_settings()?.storage_path {
+        Some(custom_path) => PathBuf::from(custom_path),
+        None => get_data_directory()?,
+    };
+    Ok(root.join(INTEGRITY_KEY_FILE))
+}
+
+/// Load the integrity key shared by the storage folder, creating it on first use
+///
+/// A key left in the data directory by an older version is moved to the storage
+/// folder, so sessions it sealed keep verifying.
+pub fn load_or_create_integrity_key() -> Result<Vec<u8>, AppError> {
+    let key_path = get_integrity_key_path()?;
+
+    let legacy_path = get_data_directory()?.join(INTEGRITY_KEY_FILE);
+    if !key_path.exists() && legacy_path != key_path && legacy_path.exists() {
+        fs::copy(&legacy_path, &key_path).map_err(|e| {
+            AppError::StorageError(format!("Failed to move integrity key: {}", e))
+        })?;
+    }
+
+    if key_path.exists() {
+        let content = fs::read_to_string(&key_path).map_err(|e| {
+            AppError::StorageError(format!("Failed to read integrity key: {}", e))
+        })?;
+        return hex::decode(content.trim())
+            .map_err(|e| AppError::ParseError(format!("Invalid integrity key: {}", e)));
+    }
+
+    let mut key = vec![0u8; 32];
+    getrandom::getrandom(&mut key).map_err(|e| {
+        AppError::StorageError(format!("Failed to generate integrity key: {}", e))
+    })?;
+
+    fs::write(&key_path, hex::encode(&key)).map_err(|e| {
+        AppError::StorageError(format!("Failed to write integrity key: {}", e))
+    })?;
+
+    Ok(key)
+}
+
+/// Chain value preceding the first verified session of a day
+fn genesis(date: NaiveDate) -> String {
+    format!("genesis:{}", date.format("%Y-%m-%d"))
+}
+
+/// Compute the chain hash of a session given the previous chain value
+pub fn compute_chain_hash(key: &[u8], previous: &str, date: NaiveDate, session: &Session) -> String {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(previous.as_bytes());
+    mac.update(b"\n");
+    mac.update(date.format("%Y-%m-%d").to_string().as_bytes());
+    mac.update(b"\n");
+    mac.update(session.to_markdown_content(date).as_bytes());
+
+    let digest = hex::encode(mac.finalize().into_bytes());
+    digest[..CHAIN_HASH_LENGTH].to_string()
+}
+
+/// Keyed tag of the last session of a day's chain, stored in the day file so that
+/// removing that session is detected
+fn compute_tip_tag(key: &[u8], date: NaiveDate, tip_hash: &str) -> String {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(b"tip\n");
+    mac.update(date.format("%Y-%m-%d").to_string().as_bytes());
+    mac.update(b"\n");
+    mac.update(tip_hash.as_bytes());
+
+    let digest = hex::encode(mac.finalize().into_bytes());
+    digest[..CHAIN_HASH_LENGTH].to_string()
+}
+
+/// Verification statuses of a day, with the stored hash of its verified chain tip
+fn analyze_day(
+    key: &[u8],
+    date: NaiveDate,
+    sessions: &[Session],
+    chain_tip: Option<&str>,
+) -> (Vec<EntryStatus>, Option<String>) {
+    let mut statuses: Vec<EntryStatus> = sessions
+        .iter()
+        .map(|s| match s.chain_hash {
+            Some(_) => EntryStatus::Verified,
+            None => EntryStatus::Unverified,
+        })
+        .collect();
+
+    // A copied line shares its identity and hash with the original, so neither can
+    // be trusted
+    let mut identities: HashMap<(String, SessionType), u32> = HashMap::new();
+    let mut hashes: HashMap<&str, u32> = HashMap::new();
+    for session in sessions {
+        *identities.entry(session.identity()).or_default() += 1;
+        if let Some(hash) = &session.chain_hash {
+            *hashes.entry(hash.as_str()).or_default() += 1;
+        }
+    }
+    for (session, status) in sessions.iter().zip(statuses.iter_mut()) {
+        if let Some(hash) = &session.chain_hash {
+            if identities[&session.identity()] > 1 || hashes[hash.as_str()] > 1 {
+                *status = EntryStatus::Tampered;
+            }
+        }
+    }
+
+    // Link each sealed session to the chain value it was sealed after
+    let genesis = genesis(date);
+    let mut predecessors: Vec<Option<&str>> = vec![None; sessions.len()];
+    for (i, session) in sessions.iter().enumerate() {
+        let Some(stored) = &session.chain_hash else {
+            continue;
+        };
+        if statuses[i] == EntryStatus::Tampered {
+            continue;
+        }
+        let mut candidates = std::iter::once(genesis.as_str()).chain(
+            sessions
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .filter_map(|(_, other)| other.chain_hash.as_deref()),
+        );
+        predecessors[i] =
+            candidates.find(|previous| compute_chain_hash(key, previous, date, session) == *stored);
+        if predecessors[i].is_none() {
+            statuses[i] = EntryStatus::Tampered;
+        }
+    }
+
+    // Each chain value, genesis included, is followed by at most one session
+    let mut successors: HashMap<&str, u32> = HashMap::new();
+    for previous in predecessors.iter().flatten() {
+        *successors.entry(previous).or_default() += 1;
+    }
+    for (i, previous) in predecessors.iter().enumerate() {
+        if previous.is_some_and(|previous| successors[previous] > 1) {
+            statuses[i] = EntryStatus::Tampered;
+        }
+    }
+
+    // A verified session nothing chains from is a tip, and only the tip recorded in
+    // the file may be one
+    let followed: HashSet<&str> = predecessors
+        .iter()
+        .zip(&statuses)
+        .filter(|(_, status)| **status == EntryStatus::Verified)
+        .filter_map(|(previous, _)| *previous)
+        .collect();
+    let mut tip = None;
+    for (session, status) in sessions.iter().zip(statuses.iter_mut()) {
+        let Some(hash) = &session.chain_hash else {
+            continue;
+        };
+        if *status != EntryStatus::Verified || followed.contains(hash.as_str()) {
+            continue;
+        }
+        if chain_tip == Some(compute_tip_tag(key, date, hash).as_str()) {
+            tip = Some(hash.clone());
+        } else {
+            *status = EntryStatus::Tampered;
+        }
+    }
+
+    (statuses, tip)
+}
+
+/// Seal `session` onto the day's chain and append it to `daily_file`
+///
+/// The session chains from the verified tip of the day, or from genesis when the
+/// day has no verified chain yet.
+pub fn append_sealed(key: &[u8], daily_file: &mut DailySessionFile, mut session: Session) {
+    let date = daily_file.date;
+    let (_, tip) = analyze_day(key, date, &daily_file.sessions, daily_file.chain_tip.as_deref());
+    let previous = tip.unwrap_or_else(|| genesis(date));
+
+    let hash = compute_chain_hash(key, &previous, date, &session);
+    daily_file.chain_tip = Some(compute_tip_tag(key, date, &hash));
+    session.chain_hash = Some(hash);
+    daily_file.add_session(session);
+}
+
+/// Verify the chain of one day. Returns one status per session, in file order.
+///
+/// A sealed session verifies when its hash chains from the day's genesis or from the
+/// *stored* hash of another session in the file, so re-sorting a day doesn't matter.
+/// Each chain value may be followed by a single session and the last one must match
+/// the tip recorded in the file. An edited entry only flags itself, a removed entry
+/// flags its neighbours in the chain, and a duplicated line flags every copy.
+pub fn verify_day_sessions(key: &[u8], daily_file: &DailySessionFile) -> Vec<EntryStatus> {
+    analyze_day(
+        key,
+        daily_file.date,
+        &daily_file.sessions,
+        daily_file.chain_tip.as_deref(),
+    )
+    .0
+}
+
+/// Check every session file and report edited or injected entries
+pub fn verify_history() -> Result<HistoryVerificationReport, AppError> {
+    let key = load_or_create_integrity_key()?;
+    verify_history_in(&key, &get_sessions_directory()?)
+}
+
+/// Same as [`verify_history`], for an explicit key and sessions directory
+pub fn verify_history_in(
+    key: &[u8],
+    sessions_dir: &Path,
+) -> Result<HistoryVerificationReport, AppError> {
+    let mut report = HistoryVerificationReport::default();
+
+    for date in list_session_dates_in(sessions_dir)? {
+        let file_path = sessions_dir.join(format!("{}.md", date.format("%Y-%m-%d")));
+        let daily_file = load_daily_file(date, &file_path)?;
+        report.days_checked += 1;
+
+        let statuses = verify_day_sessions(key, &daily_file);
+        for (session, status) in daily_file.sessions.iter().zip(statuses) {
+            match status {
+                EntryStatus::Verified => report.verified_count += 1,
+                EntryStatus::Unverified => report.unverified_count += 1,
+                EntryStatus::Tampered => {
+                    report.tampered_count += 1;
+                    report.issues.push(IntegrityIssue {
+                        date: date.format("%Y-%m-%d").to_string(),
+                        line: session.to_markdown_line(date),
+                        reason: "Entry was edited, duplicated or injected, or a neighbouring \
+                                 verified entry was removed"
+                            .to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Keep the sessions of a day that pass verification
+pub fn keep_verified_sessions(key: &[u8], daily_file: DailySessionFile) -> Vec<Session> {
+    if !daily_file.sessions.iter().any(Session::is_verified) {
+        return Vec::new();
+    }
+
+    let statuses = verify_day_sessions(key, &daily_file);
+
+    daily_file
+        .sessions
+        .into_iter()
+        .zip(statuses)
+        .filter(|(_, status)| *status == EntryStatus::Verified)
+        .map(|(session, _)| session)
+        .collect()
+}
+
+/// Prepare the sessions of another copy of a day to be merged into `daily_file`.
+/// Sessions verifying in their own copy are sealed again onto the day's chain, so
+/// the day keeps a single chain; the others are merged unverified. Sessions already
+/// present (by identity) are skipped. Returns the sessions added.
+pub fn merge_sealed_copy(
+    key: &[u8],
+    daily_file: &mut DailySessionFile,
+    copy: DailySessionFile,
+) -> Vec<Session> {
+    let statuses = verify_day_sessions(key, &copy);
+    let mut seen: HashSet<(String, SessionType)> =
+        daily_file.sessions.iter().map(Session::identity).collect();
+
+    let mut incoming: Vec<(Session, EntryStatus)> = copy
+        .sessions
+        .into_iter()
+        .zip(statuses)
+        .filter(|(session, _)| seen.insert(session.identity()))
+        .collect();
+    incoming.sort_by_key(|(session, _)| session.start_time);
+
+    let mut added = Vec::with_capacity(incoming.len());
+    for (mut session, status) in incoming {
+        session.chain_hash = None;
+        if status == EntryStatus::Verified {
+            append_sealed(key, daily_file, session);
+        } else {
+            daily_file.add_session(session);
+        }
+        added.extend(daily_file.sessions.last().cloned());
+    }
+    daily_file.sessions.sort_by_key(|s| s.start_time);
+
+    added
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::sessions::{test_session, SessionStatus, SessionType};
+
+    const KEY: &[u8] = b"test-integrity-key";
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, 10).unwrap()
+    }
+
+    fn session(hour: u32, minutes: i64, status: SessionStatus) -> Session {
+        test_session(date(), (hour, 0), minutes, status, SessionType::Focus)
+    }
+
+    fn sealed_day(sessions: Vec<Session>) -> DailySessionFile {
+        let mut file = DailySessionFile::new(date());
+        for s in sessions {
+            append_sealed(KEY, &mut file, s);
+        }
+        file
+    }
+
+    #[test]
+    fn test_untouched_chain_verifies() {
+        let file = sealed_day(vec![
+            session(9, 25, SessionStatus::Complete),
+            session(10, 25, SessionStatus::Complete),
+        ]);
+
+        let statuses = verify_day_sessions(KEY, &file);
+
+        assert_eq!(statuses, vec![EntryStatus::Verified, EntryStatus::Verified]);
+    }
+
+    #[test]
+    fn test_edited_entry_is_detected() {
+        let mut file = sealed_day(vec![
+            session(9, 12, SessionStatus::Interrupted),
+            session(10, 25, SessionStatus::Complete),
+        ]);
+        file.sessions[0].status = SessionStatus::Complete;
+
+        let statuses = verify_day_sessions(KEY, &file);
+
+        assert_eq!(statuses, vec![EntryStatus::Tampered, EntryStatus::Verified]);
+    }
+
+    #[test]
+    fn test_removed_entry_breaks_the_chain() {
+        let mut file = sealed_day(vec![
+            session(9, 25, SessionStatus::Complete),
+            session(10, 25, SessionStatus::Complete),
+            session(11, 25, SessionStatus::Complete),
+        ]);
+        file.sessions.remove(1);
+
+        let statuses = verify_day_sessions(KEY, &file);
+
+        // The successor no longer chains, and the predecessor became an unrecorded tip
+        assert_eq!(statuses, vec![EntryStatus::Tampered, EntryStatus::Tampered]);
+    }
+
+    #[test]
+    fn test_removed_tail_entry_is_detected() {
+        let mut file = sealed_day(vec![
+            session(9, 25, SessionStatus::Complete),
+            session(10, 25, SessionStatus::Complete),
+            session(11, 25, SessionStatus::Complete),
+        ]);
+        file.sessions.pop();
+
+        let statuses = verify_day_sessions(KEY, &file);
+
+        assert_eq!(statuses, vec![EntryStatus::Verified, EntryStatus::Tampered]);
+    }
+
+    #[test]
+    fn test_duplicated_line_is_detected() {
+        let mut file = sealed_day(vec![
+            session(9, 25, SessionStatus::Complete),
+            session(10, 25, SessionStatus::Complete),
+        ]);
+        let copy = file.sessions[0].clone();
+        file.sessions.push(copy);
+
+        let statuses = verify_day_sessions(KEY, &file);
+
+        assert_eq!(
+            statuses,
+            vec![EntryStatus::Tampered, EntryStatus::Verified, EntryStatus::Tampered]
+        );
+        assert_eq!(keep_verified_sessions(KEY, file).len(), 1);
+    }
+
+    #[test]
+    fn test_missing_tip_record_is_detected() {
+        let mut file = sealed_day(vec![session(9, 25, SessionStatus::Complete)]);
+        file.chain_tip = None;
+
+        let statuses = verify_day_sessions(KEY, &file);
+
+        assert_eq!(statuses, vec![EntryStatus::Tampered]);
+    }
+
+    #[test]
+    fn test_injected_entries_are_unverified_or_tampered() {
+        let mut file = sealed_day(vec![session(9, 25, SessionStatus::Complete)]);
+        file.sessions.push(session(10, 25, SessionStatus::Complete));
+        let mut forged = session(11, 25, SessionStatus::Complete);
+        forged.chain_hash = Some("0000000000000000".to_string());
+        file.sessions.push(forged);
+
+        let statuses = verify_day_sessions(KEY, &file);
+
+        assert_eq!(
+            statuses,
+            vec![EntryStatus::Verified, EntryStatus::Unverified, EntryStatus::Tampered]
+        );
+    }
+
+    #[test]
+    fn test_chains_verify_in_any_order() {
+        let mut file = sealed_day(vec![
+            session(9, 25, SessionStatus::Complete),
+            session(10, 25, SessionStatus::Complete),
+            session(11, 25, SessionStatus::Complete),
+        ]);
+        file.sessions.reverse();
+
+        let statuses = verify_day_sessions(KEY, &file);
+
+        assert_eq!(statuses, vec![EntryStatus::Verified; 3]);
+    }
+
+    #[test]
+    fn test_conflict_merge_keeps_both_chains_verified() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+
+        let write_day = |name: &str, file: DailySessionFile| {
+            fs::write(dir.join(name), file.to_markdown()).unwrap();
+        };
+
+        // Each machine sealed its own chain for the day; the merge seals the other
+        // machine's sessions again onto the local chain
+        write_day(
+            "2026-01-10.md",
+            sealed_day(vec![
+                session(10, 25, SessionStatus::Complete),
+                session(11, 25, SessionStatus::Complete),
+            ]),
+        );
+        write_day(
+            "2026-01-10.sync-conflict-20260110-184500-ABCDEFG.md",
+            sealed_day(vec![
+                session(8, 25, SessionStatus::Complete),
+                session(9, 25, SessionStatus::Complete),
+            ]),
+        );
+
+        let merged = crate::storage::conflicts::resolve_conflicts_in(dir, KEY).unwrap();
+        assert_eq!(merged.sessions_added, 2);
+        assert_eq!(merged.verified_complete_focus_added, 2);
+
+        let report = verify_history_in(KEY, dir).unwrap();
+
+        assert_eq!(report.days_checked, 1);
+        assert_eq!(report.verified_count, 4);
+        assert_eq!(report.tampered_count, 0);
+    }
+
+    #[test]
+    fn test_merge_keeps_forged_copy_entries_unverified() {
+        let mut file = sealed_day(vec![session(10, 25, SessionStatus::Complete)]);
+        let mut copy = sealed_day(vec![session(8, 25, SessionStatus::Complete)]);
+        copy.sessions[0].status = SessionStatus::Interrupted;
+
+        let added = merge_sealed_copy(KEY, &mut file, copy);
+
+        assert_eq!(added.len(), 1);
+        assert!(!added[0].is_verified());
+        assert!(!file.sessions[0].is_verified());
+        assert_eq!(
+            verify_day_sessions(KEY, &file),
+            vec![EntryStatus::Unverified, EntryStatus::Verified]
+        );
+    }
+
+    #[test]
+    fn test_chain_hash_depends_on_key() {
+        let s = session(9, 25, SessionStatus::Complete);
+
+        let a = compute_chain_hash(KEY, &genesis(date()), date(), &s);
+        let b = compute_chain_hash(b"another-key", &genesis(date()), date(), &s);
+
+        assert_eq!(a.len(), CHAIN_HASH_LENGTH);
+        assert_ne!(a, b);
+    }
+}
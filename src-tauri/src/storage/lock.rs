@@ -0,0 +1,94 @@
+//! Storage lock module
+//!
+//! The timer thread, the scheduler thread and commands all read, modify and write
+//! achievements.json and the daily session files. Each such cycle holds the storage
+//! lock so concurrent writers can't overwrite each other's changes. The lock is
+//! reentrant within a thread, so locked functions may call each other.
+
+use std::cell::Cell;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+
+thread_local! {
+    /// Number of storage guards alive on the current thread
+    static DEPTH: Cell<u32> = const { Cell::new(0) };
+}
+
+fn storage_mutex() -> &'static Mutex<()> {
+    static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+    LOCK.get_or_init(|| Mutex::new(()))
+}
+
+/// Held while a read-modify-write of the storage files is in progress
+pub struct StorageGuard {
+    _guard: Option<MutexGuard<'static, ()>>,
+}
+
+impl Drop for StorageGuard {
+    fn drop(&mut self) {
+        DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// Take the storage lock, or join the one the current thread already holds
+pub fn lock_storage() -> StorageGuard {
+    let held = DEPTH.with(|depth| {
+        let held = depth.get() > 0;
+        depth.set(depth.get() + 1);
+        held
+    });
+
+    // A panic while holding the lock leaves the files as they were written, so
+    // a poisoned lock is still usable
+    let guard = (!held).then(|| {
+        storage_mutex()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    });
+
+    StorageGuard { _guard: guard }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test_lock_is_reentrant_on_the_same_thread() {
+        let outer = lock_storage();
+        let inner = lock_storage();
+        drop(inner);
+        drop(outer);
+
+        // Released once every guard is gone
+        let other = thread::spawn(|| drop(lock_storage()));
+        other.join().unwrap();
+    }
+
+    #[test]
+    fn test_lock_serializes_threads() {
+        let counter = Arc::new(Mutex::new(Vec::new()));
+
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let counter = Arc::clone(&counter);
+                thread::spawn(move || {
+                    let _guard = lock_storage();
+                    counter.lock().unwrap().push(i);
+                    thread::sleep(std::time::Duration::from_millis(5));
+                    counter.lock().unwrap().push(i);
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        // Each thread's two entries are adjacent
+        let order = counter.lock().unwrap();
+        for pair in order.chunks(2) {
+            assert_eq!(pair[0], pair[1]);
+        }
+    }
+}
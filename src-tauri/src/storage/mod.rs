@@ -1,4 +1,19 @@
 pub mod achievements;
+pub mod backup;
+pub mod breaks;
+pub mod conflicts;
+pub mod export;
+pub mod focus_score;
+pub mod goals;
+pub mod import;
+pub mod integrity;
+pub mod lock;
+pub mod patterns;
+pub mod progression;
+pub mod records;
+pub mod reminders;
 pub mod recovery;
+pub mod reports;
 pub mod sessions;
 pub mod settings;
+pub mod stats;
@@ -0,0 +1,263 @@
+//! Focus pattern analysis module
+//!
+//! Aggregates focus sessions by hour of day and by weekday to show when the
+//! user focuses best. Sessions are bucketed by their local start time.
+
+use chrono::{Duration, Locale, NaiveDate, Timelike};
+use serde::Serialize;
+
+use crate::error::AppError;
+use crate::storage::sessions::{
+    load_sessions_in_range, DateRange, Session, SessionStatus, SessionType,
+};
+use crate::storage::settings::{load_settings, WeekStart};
+use crate::storage::stats::{day_abbreviation, resolve_locale};
+
+/// Buckets with fewer sessions than this are not ranked as best or worst slot,
+/// unless no bucket reaches it
+const MIN_SESSIONS_FOR_RANKING: u32 = 3;
+
+/// Focus statistics for one hour of the day or one weekday
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatternBucket {
+    /// Hour (0-23) or weekday (0 = first day of the configured week)
+    pub index: u32,
+    pub label: String,
+    pub focus_minutes: u32,
+    pub session_count: u32,
+    pub complete_count: u32,
+    pub interrupted_count: u32,
+    /// Share of sessions completed, from 0.0 to 1.0
+    pub completion_rate: f64,
+    /// Share of sessions interrupted, from 0.0 to 1.0
+    pub interruption_rate: f64,
+}
+
+impl PatternBucket {
+    fn new(index: u32, label: String) -> Self {
+        Self {
+            index,
+            label,
+            ..Self::default()
+        }
+    }
+
+    fn add(&mut self, session: &Session) {
+        self.focus_minutes += session.duration_seconds / 60;
+        self.session_count += 1;
+        match session.status {
+            SessionStatus::Complete => self.complete_count += 1,
+            SessionStatus::Interrupted => self.interrupted_count += 1,
+        }
+        self.completion_rate = self.complete_count as f64 / self.session_count as f64;
+        self.interruption_rate = self.interrupted_count as f64 / self.session_count as f64;
+    }
+}
+
+/// Response of the focus pattern query
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FocusPatterns {
+    pub range: DateRange,
+    pub by_hour: Vec<PatternBucket>,
+    pub by_weekday: Vec<PatternBucket>,
+    pub best_hour: Option<u32>,
+    pub worst_hour: Option<u32>,
+    pub best_weekday: Option<u32>,
+    pub worst_weekday: Option<u32>,
+}
+
+/// Pick the best and worst buckets by completion rate, breaking ties with focus minutes
+fn rank_buckets(buckets: &[PatternBucket]) -> (Option<u32>, Option<u32>) {
+    let used: Vec<&PatternBucket> = buckets.iter().filter(|b| b.session_count > 0).collect();
+    let ranked: Vec<&PatternBucket> = if used
+        .iter()
+        .any(|b| b.session_count >= MIN_SESSIONS_FOR_RANKING)
+    {
+        used.into_iter()
+            .filter(|b| b.session_count >= MIN_SESSIONS_FOR_RANKING)
+            .collect()
+    } else {
+        used
+    };
+
+    let key = |b: &&PatternBucket| (b.completion_rate, b.focus_minutes);
+    let cmp = |a: &&PatternBucket, b: &&PatternBucket| {
+        key(a)
+            .partial_cmp(&key(b))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    };
+
+    let best = ranked.iter().max_by(|a, b| cmp(a, b)).map(|b| b.index);
+    let worst = ranked.iter().min_by(|a, b| cmp(a, b)).map(|b| b.index);
+    (best, worst)
+}
+
+/// Aggregate the focus sessions of loaded days into hour and weekday buckets.
+/// Weekdays are ordered from `week_start` and labelled in `locale`. A session
+/// counts toward the weekday of the day it is filed under, so sessions after
+/// midnight follow the configured day boundary like streaks and goals do.
+pub fn analyze_focus_patterns(
+    range: &DateRange,
+    days: &[(NaiveDate, Vec<Session>)],
+    week_start: WeekStart,
+    locale: Locale,
+) -> FocusPatterns {
+    let mut by_hour: Vec<PatternBucket> = (0..24)
+        .map(|hour| PatternBucket::new(hour, format!("{:02}:00", hour)))
+        .collect();
+    let first_day = week_start.week_start_of(range.start);
+    let mut by_weekday: Vec<PatternBucket> = (0..7)
+        .map(|i| {
+            let label = day_abbreviation(first_day + Duration::days(i as i64), locale);
+            PatternBucket::new(i, label)
+        })
+        .collect();
+
+    let focus_sessions = days
+        .iter()
+        .filter(|(date, _)| range.contains(*date))
+        .flat_map(|(date, sessions)| sessions.iter().map(move |s| (*date, s)))
+        .filter(|(_, s)| s.session_type == SessionType::Focus);
+
+    for (date, session) in focus_sessions {
+        by_hour[session.start_time.hour() as usize].add(session);
+        by_weekday[week_start.day_of_week(date) as usize].add(session);
+    }
+
+    let (best_hour, worst_hour) = rank_buckets(&by_hour);
+    let (best_weekday, worst_weekday) = rank_buckets(&by_weekday);
+
+    FocusPatterns {
+        range: *range,
+        by_hour,
+        by_weekday,
+        best_hour,
+        worst_hour,
+        best_weekday,
+        worst_weekday,
+    }
+}
+
+/// Load the sessions of a range and analyze their focus patterns
+pub fn get_focus_patterns(range: &DateRange) -> Result<FocusPatterns, AppError> {
+    let settings = load_settings()?;
+    let days = load_sessions_in_range(range)?;
+    Ok(analyze_focus_patterns(
+        range,
+        &days,
+        settings.week_start,
+        resolve_locale(settings.locale.as_deref()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::sessions::test_session;
+
+    fn session(day: u32, hour: u32, status: SessionStatus) -> Session {
+        let date = NaiveDate::from_ymd_opt(2026, 1, day).unwrap();
+        test_session(date, (hour, 0), 25, status, SessionType::Focus)
+    }
+
+    fn range() -> DateRange {
+        DateRange {
+            start: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            end: NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_buckets_by_hour_and_weekday() {
+        // 2026-01-05 is a Monday, 2026-01-06 a Tuesday
+        let monday = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+        let tuesday = NaiveDate::from_ymd_opt(2026, 1, 6).unwrap();
+        let mut monday_sessions = vec![
+            session(5, 9, SessionStatus::Complete),
+            session(5, 9, SessionStatus::Interrupted),
+        ];
+        monday_sessions.push(Session::new(
+            monday_sessions[0].end_time,
+            monday_sessions[0].end_time + Duration::minutes(5),
+            SessionStatus::Complete,
+            SessionType::Break,
+        ));
+        let days = vec![
+            (monday, monday_sessions),
+            (tuesday, vec![session(6, 14, SessionStatus::Complete)]),
+        ];
+
+        let patterns = analyze_focus_patterns(&range(), &days, WeekStart::Monday, Locale::en_US);
+
+        assert_eq!(patterns.by_hour.len(), 24);
+        assert_eq!(patterns.by_hour[9].session_count, 2);
+        assert_eq!(patterns.by_hour[9].completion_rate, 0.5);
+        assert_eq!(patterns.by_hour[9].interruption_rate, 0.5);
+        assert_eq!(patterns.by_hour[14].focus_minutes, 25);
+        assert_eq!(patterns.by_weekday[0].label, "Mon");
+        assert_eq!(patterns.by_weekday[0].session_count, 2);
+        assert_eq!(patterns.by_weekday[1].complete_count, 1);
+        assert_eq!(patterns.best_hour, Some(14));
+        assert_eq!(patterns.worst_hour, Some(9));
+    }
+
+    #[test]
+    fn test_weekdays_follow_week_start_and_locale() {
+        // 2026-01-05 is a Monday
+        let monday = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+        let days = vec![(monday, vec![session(5, 9, SessionStatus::Complete)])];
+
+        let patterns = analyze_focus_patterns(&range(), &days, WeekStart::Sunday, Locale::de_DE);
+
+        assert_eq!(patterns.by_weekday[0].label, "So");
+        assert_eq!(patterns.by_weekday[1].label, "Mo");
+        assert_eq!(patterns.by_weekday[1].session_count, 1);
+        assert_eq!(patterns.best_weekday, Some(1));
+    }
+
+    #[test]
+    fn test_sessions_after_midnight_count_toward_their_logical_day() {
+        // 2026-01-05 is a Monday; its 01:00 session on Tuesday belongs to Monday
+        let monday = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+        let days = vec![(monday, vec![session(6, 1, SessionStatus::Complete)])];
+
+        let patterns = analyze_focus_patterns(&range(), &days, WeekStart::Monday, Locale::en_US);
+
+        assert_eq!(patterns.by_weekday[0].session_count, 1);
+        assert_eq!(patterns.by_weekday[1].session_count, 0);
+        assert_eq!(patterns.by_hour[1].session_count, 1);
+    }
+
+    #[test]
+    fn test_ranking_prefers_buckets_with_enough_sessions() {
+        let day = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+        let days = vec![(
+            day,
+            vec![
+                session(5, 8, SessionStatus::Complete),
+                session(5, 10, SessionStatus::Complete),
+                session(5, 10, SessionStatus::Complete),
+                session(5, 10, SessionStatus::Interrupted),
+                session(5, 16, SessionStatus::Complete),
+                session(5, 16, SessionStatus::Interrupted),
+                session(5, 16, SessionStatus::Interrupted),
+            ],
+        )];
+
+        let patterns = analyze_focus_patterns(&range(), &days, WeekStart::Monday, Locale::en_US);
+
+        // 08:00 has a perfect rate but a single session
+        assert_eq!(patterns.best_hour, Some(10));
+        assert_eq!(patterns.worst_hour, Some(16));
+    }
+
+    #[test]
+    fn test_empty_range_has_no_best_slot() {
+        let patterns = analyze_focus_patterns(&range(), &[], WeekStart::Monday, Locale::en_US);
+
+        assert_eq!(patterns.best_hour, None);
+        assert_eq!(patterns.worst_weekday, None);
+    }
+}
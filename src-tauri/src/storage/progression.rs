@@ -0,0 +1,206 @@
+//! Progression module
+//!
+//! Turns focus minutes, completed sessions, streak days and unlocked achievements
+//! into XP, and XP into levels. Level `n` starts at `step * n * (n - 1) / 2` XP, so
+//! each level needs `step` more XP than the one before. XP is derived from the
+//! history on every update; the stored XP and level only serve to detect level-ups.
+
+use serde::Serialize;
+
+use crate::error::AppError;
+use crate::storage::achievements::{
+    load_achievement_stats, load_achievements, AchievementStats, AchievementsData,
+};
+use crate::storage::settings::{load_settings, ProgressionRules};
+
+/// XP earned from each source
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XpBreakdown {
+    pub focus_minutes: u32,
+    pub sessions: u32,
+    pub streak_days: u32,
+    pub achievements: u32,
+}
+
+impl XpBreakdown {
+    pub fn total(&self) -> u32 {
+        self.focus_minutes
+            .saturating_add(self.sessions)
+            .saturating_add(self.streak_days)
+            .saturating_add(self.achievements)
+    }
+}
+
+/// XP and level for the UI
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Progression {
+    pub xp: u32,
+    pub level: u32,
+    /// XP at which the current level started
+    pub level_xp: u32,
+    /// XP at which the next level starts
+    pub next_level_xp: u32,
+    pub breakdown: XpBreakdown,
+}
+
+/// A level reached since the last progression update
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelUp {
+    pub previous_level: u32,
+    pub level: u32,
+    pub xp: u32,
+}
+
+/// XP earned by the aggregated history and `unlocked_count` achievements
+pub fn compute_xp(
+    rules: &ProgressionRules,
+    stats: &AchievementStats,
+    unlocked_count: u32,
+) -> XpBreakdown {
+    XpBreakdown {
+        focus_minutes: stats
+            .total_focus_minutes
+            .saturating_mul(rules.xp_per_focus_minute),
+        sessions: stats.total_sessions.saturating_mul(rules.xp_per_session),
+        streak_days: stats.streak_days.saturating_mul(rules.xp_per_streak_day),
+        achievements: unlocked_count.saturating_mul(rules.xp_per_achievement),
+    }
+}
+
+/// XP at which `level` starts. A zero step is treated as 1.
+pub fn level_threshold(rules: &ProgressionRules, level: u32) -> u64 {
+    let level = level.max(1) as u64;
+    rules.level_xp_step.max(1) as u64 * level * (level - 1) / 2
+}
+
+/// Level reached with `xp`, starting at level 1
+pub fn level_for_xp(rules: &ProgressionRules, xp: u32) -> u32 {
+    let mut level = 1;
+    while level_threshold(rules, level + 1) <= xp as u64 {
+        level += 1;
+    }
+    level
+}
+
+/// XP and level earned by the aggregated history
+pub fn compute_progression(
+    rules: &ProgressionRules,
+    stats: &AchievementStats,
+    unlocked_count: u32,
+) -> Progression {
+    let breakdown = compute_xp(rules, stats, unlocked_count);
+    let xp = breakdown.total();
+    let level = level_for_xp(rules, xp);
+
+    Progression {
+        xp,
+        level,
+        level_xp: level_threshold(rules, level).min(u32::MAX as u64) as u32,
+        next_level_xp: level_threshold(rules, level + 1).min(u32::MAX as u64) as u32,
+        breakdown,
+    }
+}
+
+/// Store `progression` in `achievements`. Returns the level-up, if the level rose
+/// since the last update. The first update only records the level.
+pub fn record_progression(
+    achievements: &mut AchievementsData,
+    progression: &Progression,
+) -> Option<LevelUp> {
+    let previous_level = achievements.level;
+    achievements.xp = progression.xp;
+    achievements.level = progression.level;
+
+    (previous_level > 0 && progression.level > previous_level).then_some(LevelUp {
+        previous_level,
+        level: progression.level,
+        xp: progression.xp,
+    })
+}
+
+/// Current XP and level
+pub fn get_progression() -> Result<Progression, AppError> {
+    let settings = load_settings()?;
+    let achievements = load_achievements()?;
+    let stats = load_achievement_stats(&achievements)?;
+
+    Ok(compute_progression(
+        &settings.progression,
+        &stats,
+        achievements.unlocked_achievements.len() as u32,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats() -> AchievementStats {
+        AchievementStats {
+            total_sessions: 12,
+            total_focus_minutes: 310,
+            streak_days: 8,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_compute_xp() {
+        let breakdown = compute_xp(&ProgressionRules::default(), &stats(), 2);
+
+        assert_eq!(breakdown.focus_minutes, 310);
+        assert_eq!(breakdown.sessions, 120);
+        assert_eq!(breakdown.streak_days, 40);
+        assert_eq!(breakdown.achievements, 200);
+        assert_eq!(breakdown.total(), 670);
+    }
+
+    #[test]
+    fn test_level_thresholds() {
+        let rules = ProgressionRules::default();
+
+        assert_eq!(level_threshold(&rules, 1), 0);
+        assert_eq!(level_threshold(&rules, 2), 100);
+        assert_eq!(level_threshold(&rules, 4), 600);
+        assert_eq!(level_for_xp(&rules, 0), 1);
+        assert_eq!(level_for_xp(&rules, 99), 1);
+        assert_eq!(level_for_xp(&rules, 100), 2);
+        assert_eq!(level_for_xp(&rules, 670), 4);
+    }
+
+    #[test]
+    fn test_compute_progression() {
+        let progression = compute_progression(&ProgressionRules::default(), &stats(), 2);
+
+        assert_eq!(progression.xp, 670);
+        assert_eq!(progression.level, 4);
+        assert_eq!(progression.level_xp, 600);
+        assert_eq!(progression.next_level_xp, 1000);
+    }
+
+    #[test]
+    fn test_record_progression_detects_level_up() {
+        let rules = ProgressionRules::default();
+        let mut achievements = AchievementsData::default();
+
+        // The first update only records the level
+        let first = compute_progression(&rules, &stats(), 0);
+        assert_eq!(record_progression(&mut achievements, &first), None);
+        assert_eq!((achievements.xp, achievements.level), (470, 3));
+
+        let same = compute_progression(&rules, &stats(), 0);
+        assert_eq!(record_progression(&mut achievements, &same), None);
+
+        let higher = compute_progression(&rules, &stats(), 2);
+        assert_eq!(
+            record_progression(&mut achievements, &higher),
+            Some(LevelUp {
+                previous_level: 3,
+                level: 4,
+                xp: 670,
+            })
+        );
+    }
+}
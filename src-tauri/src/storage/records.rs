@@ -0,0 +1,447 @@
+//! Personal records and trends module
+//!
+//! Scans the whole session history for personal bests and compares the current
+//! week and month with the previous ones.
+
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::error::AppError;
+use crate::storage::achievements::{compute_streaks, load_achievements};
+use crate::storage::sessions::{
+    list_session_dates, load_sessions_for_date, DailySummary, Session, SessionType,
+};
+use crate::storage::settings::{load_settings, StreakRules, WeekStart};
+use crate::storage::stats::{week_key, GroupBy};
+
+/// Focus minutes of a single day
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DayRecord {
+    pub date: String,
+    pub focus_minutes: u32,
+}
+
+/// Focus minutes of an ISO week
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeekRecord {
+    pub week: String,
+    pub start_date: String,
+    pub focus_minutes: u32,
+}
+
+/// Longest single focus session
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionRecord {
+    pub date: String,
+    pub start_time: String,
+    pub duration_minutes: u32,
+}
+
+/// Most focus sessions in a day
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionCountRecord {
+    pub date: String,
+    pub session_count: u32,
+}
+
+/// Longest streak. Dates are `None` when the record comes from `AchievementsData`
+/// and no longer matches the session files (e.g. after days were deleted).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreakRecord {
+    pub length: u32,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalRecords {
+    pub best_day: Option<DayRecord>,
+    pub best_week: Option<WeekRecord>,
+    pub longest_session: Option<SessionRecord>,
+    pub most_sessions_in_day: Option<SessionCountRecord>,
+    pub longest_streak: StreakRecord,
+}
+
+/// Current period compared with the same elapsed part of the previous one
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodComparison {
+    pub current_focus_minutes: u32,
+    pub previous_focus_minutes: u32,
+    /// `None` when the previous period had no focus time
+    pub focus_minutes_delta_percent: Option<f64>,
+    pub current_sessions: u32,
+    pub previous_sessions: u32,
+    pub sessions_delta_percent: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trends {
+    pub week_over_week: PeriodComparison,
+    pub month_over_month: PeriodComparison,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordsAndTrends {
+    pub records: PersonalRecords,
+    pub trends: Trends,
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+fn delta_percent(current: u32, previous: u32) -> Option<f64> {
+    if previous == 0 {
+        return None;
+    }
+    Some((current as f64 - previous as f64) / previous as f64 * 100.0)
+}
+
+fn compare(
+    summaries: &BTreeMap<NaiveDate, DailySummary>,
+    current: (NaiveDate, NaiveDate),
+    previous: (NaiveDate, NaiveDate),
+) -> PeriodComparison {
+    let totals = |(start, end): (NaiveDate, NaiveDate)| {
+        summaries
+            .range(start..=end)
+            .fold((0, 0), |(minutes, sessions), (_, s)| {
+                (
+                    minutes + s.total_focus_minutes,
+                    sessions + s.complete_count + s.partial_count,
+                )
+            })
+    };
+    let (current_focus_minutes, current_sessions) = totals(current);
+    let (previous_focus_minutes, previous_sessions) = totals(previous);
+
+    PeriodComparison {
+        current_focus_minutes,
+        previous_focus_minutes,
+        focus_minutes_delta_percent: delta_percent(current_focus_minutes, previous_focus_minutes),
+        current_sessions,
+        previous_sessions,
+        sessions_delta_percent: delta_percent(current_sessions, previous_sessions),
+    }
+}
+
+/// Compute records and trends from the loaded history.
+/// The longest streak follows `streak_rules` and the `frozen` days;
+/// `recorded_longest_streak` is the value kept in `AchievementsData`.
+pub fn compute_records_and_trends(
+    days: &[(NaiveDate, Vec<Session>)],
+    today: NaiveDate,
+    week_start: WeekStart,
+    streak_rules: &StreakRules,
+    frozen: &BTreeSet<NaiveDate>,
+    recorded_longest_streak: u32,
+) -> RecordsAndTrends {
+    let summaries: BTreeMap<NaiveDate, DailySummary> = days
+        .iter()
+        .map(|(date, sessions)| (*date, DailySummary::from_sessions(sessions)))
+        .collect();
+
+    let best_day = summaries
+        .iter()
+        .filter(|(_, s)| s.total_focus_minutes > 0)
+        .max_by_key(|(date, s)| (s.total_focus_minutes, std::cmp::Reverse(**date)))
+        .map(|(date, s)| DayRecord {
+            date: format_date(*date),
+            focus_minutes: s.total_focus_minutes,
+        });
+
+    let mut weeks: BTreeMap<NaiveDate, u32> = BTreeMap::new();
+    for (date, summary) in &summaries {
+        *weeks.entry(week_start.week_start_of(*date)).or_default() += summary.total_focus_minutes;
+    }
+    let best_week = weeks
+        .iter()
+        .filter(|(_, minutes)| **minutes > 0)
+        .max_by_key(|(start, minutes)| (**minutes, std::cmp::Reverse(**start)))
+        .map(|(start, minutes)| WeekRecord {
+            week: week_key(*start),
+            start_date: format_date(*start),
+            focus_minutes: *minutes,
+        });
+
+    let longest_session = days
+        .iter()
+        .flat_map(|(date, sessions)| sessions.iter().map(move |s| (date, s)))
+        .filter(|(_, s)| s.session_type == SessionType::Focus)
+        .max_by_key(|(_, s)| (s.duration_seconds, std::cmp::Reverse(s.start_time)))
+        .map(|(date, s)| SessionRecord {
+            date: format_date(*date),
+            start_time: s.start_time.to_rfc3339(),
+            duration_minutes: s.duration_seconds / 60,
+        });
+
+    let most_sessions_in_day = summaries
+        .iter()
+        .map(|(date, s)| (date, s.complete_count + s.partial_count))
+        .filter(|(_, count)| *count > 0)
+        .max_by_key(|(date, count)| (*count, std::cmp::Reverse(**date)))
+        .map(|(date, count)| SessionCountRecord {
+            date: format_date(*date),
+            session_count: count,
+        });
+
+    let longest_streak = match compute_streaks(&summaries, streak_rules, frozen, today).longest {
+        Some(run) if run.length >= recorded_longest_streak => StreakRecord {
+            length: run.length,
+            start_date: Some(format_date(run.start)),
+            end_date: Some(format_date(run.end)),
+        },
+        _ => StreakRecord {
+            length: recorded_longest_streak,
+            start_date: None,
+            end_date: None,
+        },
+    };
+
+    // Compare to date: e.g. Monday-Wednesday of this week vs Monday-Wednesday of last week
+    let current_week_start = week_start.week_start_of(today);
+    let elapsed_days = (today - current_week_start).num_days();
+    let previous_week_start = current_week_start - Duration::days(7);
+    let week_over_week = compare(
+        &summaries,
+        (current_week_start, today),
+        (
+            previous_week_start,
+            previous_week_start + Duration::days(elapsed_days),
+        ),
+    );
+
+    let month_start = GroupBy::Month.period_start(today);
+    let previous_month_end = month_start - Duration::days(1);
+    let previous_month_start = GroupBy::Month.period_start(previous_month_end);
+    let previous_month_to_date = previous_month_start
+        .with_day(today.day())
+        .unwrap_or(previous_month_end);
+    let month_over_month = compare(
+        &summaries,
+        (month_start, today),
+        (previous_month_start, previous_month_to_date),
+    );
+
+    RecordsAndTrends {
+        records: PersonalRecords {
+            best_day,
+            best_week,
+            longest_session,
+            most_sessions_in_day,
+            longest_streak,
+        },
+        trends: Trends {
+            week_over_week,
+            month_over_month,
+        },
+    }
+}
+
+/// Load the whole history and compute records and trends
+pub fn get_records_and_trends() -> Result<RecordsAndTrends, AppError> {
+    let mut days = Vec::new();
+    for date in list_session_dates()? {
+        let sessions = load_sessions_for_date(date)?;
+        if !sessions.is_empty() {
+            days.push((date, sessions));
+        }
+    }
+
+    let achievements = load_achievements()?;
+    let settings = load_settings()?;
+    Ok(compute_records_and_trends(
+        &days,
+        settings.logical_date(Local::now().naive_local()),
+        settings.week_start,
+        &settings.streak,
+        &achievements.frozen_dates(),
+        achievements.longest_streak,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::sessions::{test_session, SessionStatus};
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+    }
+
+    fn day(d: NaiveDate, sessions: &[(u32, i64, SessionStatus)]) -> (NaiveDate, Vec<Session>) {
+        (
+            d,
+            sessions
+                .iter()
+                .map(|(hour, minutes, status)| {
+                    test_session(d, (*hour, 0), *minutes, *status, SessionType::Focus)
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_personal_records() {
+        let days = vec![
+            day(date(1, 5), &[(9, 25, SessionStatus::Complete)]),
+            day(
+                date(1, 6),
+                &[
+                    (9, 25, SessionStatus::Complete),
+                    (10, 50, SessionStatus::Complete),
+                    (11, 10, SessionStatus::Interrupted),
+                ],
+            ),
+            day(date(1, 7), &[(9, 25, SessionStatus::Complete)]),
+            day(date(1, 20), &[(9, 25, SessionStatus::Interrupted)]),
+        ];
+
+        let result = compute_records_and_trends(
+            &days,
+            date(1, 21),
+            WeekStart::Monday,
+            &StreakRules::default(),
+            &BTreeSet::new(),
+            2,
+        );
+        let records = result.records;
+
+        assert_eq!(
+            records.best_day,
+            Some(DayRecord {
+                date: "2026-01-06".to_string(),
+                focus_minutes: 85
+            })
+        );
+        assert_eq!(records.best_week.unwrap().week, "2026-W02");
+        assert_eq!(records.longest_session.unwrap().duration_minutes, 50);
+        assert_eq!(records.most_sessions_in_day.unwrap().session_count, 3);
+        assert_eq!(
+            records.longest_streak,
+            StreakRecord {
+                length: 3,
+                start_date: Some("2026-01-05".to_string()),
+                end_date: Some("2026-01-07".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_longest_streak_falls_back_to_recorded_value() {
+        let days = vec![day(date(1, 5), &[(9, 25, SessionStatus::Complete)])];
+
+        let result = compute_records_and_trends(
+            &days,
+            date(1, 6),
+            WeekStart::Monday,
+            &StreakRules::default(),
+            &BTreeSet::new(),
+            10,
+        );
+
+        assert_eq!(result.records.longest_streak.length, 10);
+        assert_eq!(result.records.longest_streak.start_date, None);
+    }
+
+    #[test]
+    fn test_week_over_week_follows_week_start() {
+        // 2026-02-09 is a Monday, 2026-02-08 a Sunday
+        let days = vec![
+            day(date(2, 1), &[(9, 25, SessionStatus::Complete)]),
+            day(date(2, 8), &[(9, 25, SessionStatus::Complete)]),
+            day(date(2, 9), &[(9, 25, SessionStatus::Complete)]),
+        ];
+        let trends = |week_start| {
+            compute_records_and_trends(
+                &days,
+                date(2, 9),
+                week_start,
+                &StreakRules::default(),
+                &BTreeSet::new(),
+                0,
+            )
+            .trends
+            .week_over_week
+        };
+
+        let monday = trends(WeekStart::Monday);
+        assert_eq!(monday.current_focus_minutes, 25);
+        assert_eq!(monday.previous_focus_minutes, 0);
+
+        // Sunday to Monday this week vs Sunday to Monday last week
+        let sunday = trends(WeekStart::Sunday);
+        assert_eq!(sunday.current_focus_minutes, 50);
+        assert_eq!(sunday.previous_focus_minutes, 25);
+    }
+
+    #[test]
+    fn test_best_week_follows_week_start() {
+        // 2026-02-08 is a Sunday
+        let days = vec![
+            day(date(2, 1), &[(9, 25, SessionStatus::Complete)]),
+            day(date(2, 8), &[(9, 25, SessionStatus::Complete)]),
+            day(date(2, 9), &[(9, 25, SessionStatus::Complete)]),
+        ];
+
+        let result = compute_records_and_trends(
+            &days,
+            date(2, 10),
+            WeekStart::Sunday,
+            &StreakRules::default(),
+            &BTreeSet::new(),
+            0,
+        );
+        let best_week = result.records.best_week.unwrap();
+
+        assert_eq!(best_week.start_date, "2026-02-08");
+        assert_eq!(best_week.week, "2026-W07");
+        assert_eq!(best_week.focus_minutes, 50);
+    }
+
+    #[test]
+    fn test_trends_compare_same_elapsed_days() {
+        // 2026-02-11 is a Wednesday
+        let days = vec![
+            day(date(2, 2), &[(9, 25, SessionStatus::Complete)]),
+            day(date(2, 6), &[(9, 25, SessionStatus::Complete)]),
+            day(date(2, 9), &[(9, 25, SessionStatus::Complete)]),
+            day(date(2, 10), &[(9, 25, SessionStatus::Complete)]),
+            day(date(1, 3), &[(9, 100, SessionStatus::Complete)]),
+        ];
+
+        let trends = compute_records_and_trends(
+            &days,
+            date(2, 11),
+            WeekStart::Monday,
+            &StreakRules::default(),
+            &BTreeSet::new(),
+            0,
+        )
+        .trends;
+
+        // Friday 6th is past the elapsed part of last week
+        assert_eq!(trends.week_over_week.previous_focus_minutes, 25);
+        assert_eq!(trends.week_over_week.current_focus_minutes, 50);
+        assert_eq!(
+            trends.week_over_week.focus_minutes_delta_percent,
+            Some(100.0)
+        );
+        assert_eq!(trends.month_over_month.current_sessions, 4);
+        assert_eq!(trends.month_over_month.previous_focus_minutes, 100);
+        assert_eq!(
+            trends.month_over_month.focus_minutes_delta_percent,
+            Some(0.0)
+        );
+    }
+}
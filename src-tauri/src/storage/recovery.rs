@@ -141,6 +141,7 @@ pub fn check_and_recover_session() -> Result<Option<Session>, AppError> {
     
     let session = Session::new(start_local, end_local, SessionStatus::Interrupted, session_type);
     
+    // The recovery file is plain JSON anyone can edit, so the session isn't sealed
     save_session(session.clone())?;
     
     delete_recovery_file()?;
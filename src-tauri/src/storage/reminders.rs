@@ -0,0 +1,294 @@
+//! Reminders module
+//!
+//! Decides when to warn that the current streak is about to end. The reminder is
+//! sent once per day, after the configured time, outside quiet hours, and only
+//! while today still lacks a qualifying session.
+
+use chrono::{Local, NaiveDateTime, NaiveTime};
+
+use crate::error::AppError;
+use crate::storage::achievements::{load_achievements, save_achievements, streak_day, StreakDay};
+use crate::storage::lock::lock_storage;
+use crate::storage::sessions::DateRange;
+use crate::storage::settings::{
+    load_settings, logical_date, time_since_day_start, StreakReminderSettings,
+};
+use crate::storage::stats::{load_daily_summaries, load_verified_daily_summaries};
+
+/// Whether the streak-at-risk reminder should be sent at `now`
+pub fn streak_reminder_due(
+    reminder: &StreakReminderSettings,
+    day_starts_at: NaiveTime,
+    now: NaiveDateTime,
+    today: StreakDay,
+    current_streak: u32,
+    last_sent: Option<&str>,
+) -> bool {
+    if !reminder.enabled || current_streak == 0 || today != StreakDay::Missed {
+        return false;
+    }
+
+    let today_str = logical_date(now, day_starts_at)
+        .format("%Y-%m-%d")
+        .to_string();
+    if last_sent == Some(today_str.as_str()) {
+        return false;
+    }
+
+    if reminder.quiet_hours.is_some_and(|q| q.contains(now.time())) {
+        return false;
+    }
+
+    time_since_day_start(now.time(), day_starts_at)
+        >= time_since_day_start(reminder.time, day_starts_at)
+}
+
+/// Notification body, e.g. "Your 12-day streak ends at midnight"
+pub fn reminder_message(
+    current_streak: u32,
+    streak_freezes: u32,
+    day_starts_at: NaiveTime,
+) -> String {
+    let end = if day_starts_at == NaiveTime::MIN {
+        "midnight".to_string()
+    } else {
+        day_starts_at.format("%H:%M").to_string()
+    };
+
+    if streak_freezes > 0 {
+        format!(
+            "Your {}-day streak needs a session before {}, or a streak freeze will be used",
+            current_streak, end
+        )
+    } else {
+        format!("Your {}-day streak ends at {}", current_streak, end)
+    }
+}
+
+/// Check whether the streak-at-risk reminder is due now. When it is, it is
+/// recorded as sent for today and its message is returned.
+pub fn check_streak_reminder() -> Result<Option<String>, AppError> {
+    let _lock = lock_storage();
+    let settings = load_settings()?;
+    let now = Local::now().naive_local();
+    let today = settings.logical_date(now);
+
+    let range = DateRange {
+        start: today,
+        end: today,
+    };
+    let summaries = if settings.count_only_verified_sessions {
+        load_verified_daily_summaries(&range)?
+    } else {
+        load_daily_summaries(&range)?
+    };
+    let summary = summaries
+        .into_iter()
+        .next()
+        .map(|(_, s)| s)
+        .unwrap_or_default();
+    let status = streak_day(&settings.streak, today, &summary);
+
+    let mut achievements = load_achievements()?;
+    if !streak_reminder_due(
+        &settings.streak_reminder,
+        settings.day_starts_at,
+        now,
+        status,
+        achievements.current_streak,
+        achievements.last_streak_reminder.as_deref(),
+    ) {
+        return Ok(None);
+    }
+
+    achievements.last_streak_reminder = Some(today.format("%Y-%m-%d").to_string());
+    save_achievements(&achievements)?;
+
+    Ok(Some(reminder_message(
+        achievements.current_streak,
+        achievements.streak_freezes,
+        settings.day_starts_at,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::settings::QuietHours;
+    use chrono::NaiveDate;
+
+    fn time(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    fn at(day: u32, h: u32, m: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 10, day)
+            .unwrap()
+            .and_time(time(h, m))
+    }
+
+    #[test]
+    fn test_reminder_due_after_configured_time() {
+        let reminder = StreakReminderSettings::default();
+
+        assert!(!streak_reminder_due(
+            &reminder,
+            NaiveTime::MIN,
+            at(14, 19, 59),
+            StreakDay::Missed,
+            12,
+            None
+        ));
+        assert!(streak_reminder_due(
+            &reminder,
+            NaiveTime::MIN,
+            at(14, 20, 0),
+            StreakDay::Missed,
+            12,
+            None
+        ));
+    }
+
+    #[test]
+    fn test_reminder_not_due_when_streak_safe() {
+        let reminder = StreakReminderSettings::default();
+        let now = at(14, 21, 0);
+
+        assert!(!streak_reminder_due(
+            &reminder,
+            NaiveTime::MIN,
+            now,
+            StreakDay::Met,
+            12,
+            None
+        ));
+        assert!(!streak_reminder_due(
+            &reminder,
+            NaiveTime::MIN,
+            now,
+            StreakDay::Rest,
+            12,
+            None
+        ));
+        assert!(!streak_reminder_due(
+            &reminder,
+            NaiveTime::MIN,
+            now,
+            StreakDay::Missed,
+            0,
+            None
+        ));
+
+        let disabled = StreakReminderSettings {
+            enabled: false,
+            ..Default::default()
+        };
+        assert!(!streak_reminder_due(
+            &disabled,
+            NaiveTime::MIN,
+            now,
+            StreakDay::Missed,
+            12,
+            None
+        ));
+    }
+
+    #[test]
+    fn test_reminder_sent_once_per_day() {
+        let reminder = StreakReminderSettings::default();
+
+        assert!(!streak_reminder_due(
+            &reminder,
+            NaiveTime::MIN,
+            at(14, 22, 0),
+            StreakDay::Missed,
+            12,
+            Some("2026-10-14")
+        ));
+        assert!(streak_reminder_due(
+            &reminder,
+            NaiveTime::MIN,
+            at(14, 22, 0),
+            StreakDay::Missed,
+            12,
+            Some("2026-10-13")
+        ));
+    }
+
+    #[test]
+    fn test_reminder_respects_quiet_hours() {
+        let reminder = StreakReminderSettings {
+            quiet_hours: Some(QuietHours {
+                start: time(21, 0),
+                end: time(7, 0),
+            }),
+            ..Default::default()
+        };
+
+        assert!(streak_reminder_due(
+            &reminder,
+            NaiveTime::MIN,
+            at(14, 20, 30),
+            StreakDay::Missed,
+            3,
+            None
+        ));
+        assert!(!streak_reminder_due(
+            &reminder,
+            NaiveTime::MIN,
+            at(14, 21, 30),
+            StreakDay::Missed,
+            3,
+            None
+        ));
+    }
+
+    #[test]
+    fn test_reminder_with_late_day_start() {
+        let reminder = StreakReminderSettings::default();
+        let day_starts_at = time(4, 0);
+
+        // 01:00 on the 15th still belongs to the 14th, after the 20:00 reminder time
+        assert!(streak_reminder_due(
+            &reminder,
+            day_starts_at,
+            at(15, 1, 0),
+            StreakDay::Missed,
+            5,
+            None
+        ));
+        assert!(!streak_reminder_due(
+            &reminder,
+            day_starts_at,
+            at(15, 1, 0),
+            StreakDay::Missed,
+            5,
+            Some("2026-10-14")
+        ));
+        // 05:00 on the 15th is early in a new day
+        assert!(!streak_reminder_due(
+            &reminder,
+            day_starts_at,
+            at(15, 5, 0),
+            StreakDay::Missed,
+            5,
+            None
+        ));
+    }
+
+    #[test]
+    fn test_reminder_message() {
+        assert_eq!(
+            reminder_message(12, 0, NaiveTime::MIN),
+            "Your 12-day streak ends at midnight"
+        );
+        assert_eq!(
+            reminder_message(3, 0, time(4, 0)),
+            "Your 3-day streak ends at 04:00"
+        );
+        assert_eq!(
+            reminder_message(12, 1, NaiveTime::MIN),
+            "Your 12-day streak needs a session before midnight, or a streak freeze will be used"
+        );
+    }
+}
@@ -0,0 +1,421 @@
+//! Periodic review reports module
+//!
+//! Writes a markdown review of each finished week and month to a `reports/`
+//! folder next to the `sessions/` folder, e.g. `reports/2026-W41.md` and
+//! `reports/2026-10.md`, so they land in the same notes vault.
+
+use chrono::{DateTime, Duration, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::error::AppError;
+use crate::storage::achievements::{
+    get_all_achievements, load_achievements, streaks_as_of, AchievementsData, Streaks,
+};
+use crate::storage::sessions::{
+    atomic_write, get_sessions_directory, load_sessions_in_range, DailySummary, DateRange, Session,
+    SessionType,
+};
+use crate::storage::settings::{load_settings, WeekStart};
+use crate::storage::stats::{week_key, GroupBy};
+
+/// Kind of review report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportKind {
+    Weekly,
+    Monthly,
+}
+
+impl ReportKind {
+    fn title(&self) -> &'static str {
+        match self {
+            ReportKind::Weekly => "Weekly Review",
+            ReportKind::Monthly => "Monthly Review",
+        }
+    }
+}
+
+/// A week or month covered by a report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReportPeriod {
+    pub kind: ReportKind,
+    pub range: DateRange,
+}
+
+impl ReportPeriod {
+    /// The period of `kind` containing `date`, weeks starting on `week_start`
+    pub fn containing(kind: ReportKind, date: NaiveDate, week_start: WeekStart) -> Self {
+        let start = match kind {
+            ReportKind::Weekly => week_start.week_start_of(date),
+            ReportKind::Monthly => GroupBy::Month.period_start(date),
+        };
+        let end = match kind {
+            ReportKind::Weekly => start + Duration::days(6),
+            ReportKind::Monthly => GroupBy::Month.next_period_start(start) - Duration::days(1),
+        };
+        Self {
+            kind,
+            range: DateRange { start, end },
+        }
+    }
+
+    /// The last finished period of `kind` before `today`
+    pub fn previous(kind: ReportKind, today: NaiveDate, week_start: WeekStart) -> Self {
+        let current = Self::containing(kind, today, week_start);
+        Self::containing(kind, current.range.start - Duration::days(1), week_start)
+    }
+
+    /// Report key and file stem, e.g. `2026-W41` or `2026-10`
+    pub fn key(&self) -> String {
+        match self.kind {
+            ReportKind::Weekly => week_key(self.range.start),
+            ReportKind::Monthly => GroupBy::Month.period_key(self.range.start),
+        }
+    }
+}
+
+fn plural(count: u32) -> &'static str {
+    if count == 1 {
+        ""
+    } else {
+        "s"
+    }
+}
+
+/// Local date an achievement was unlocked, from its RFC 3339 timestamp
+fn unlocked_on(unlocked_at: &str) -> Option<NaiveDate> {
+    DateTime::parse_from_rfc3339(unlocked_at)
+        .ok()
+        .map(|dt| dt.with_timezone(&Local).date_naive())
+}
+
+/// Render the markdown review of a period. `streaks` are computed as of the
+/// period's last day.
+pub fn render_report(
+    period: &ReportPeriod,
+    days: &[(NaiveDate, Vec<Session>)],
+    achievements: &AchievementsData,
+    streaks: &Streaks,
+) -> String {
+    let days: Vec<&(NaiveDate, Vec<Session>)> = days
+        .iter()
+        .filter(|(date, _)| period.range.contains(*date))
+        .collect();
+
+    let mut summary = DailySummary::default();
+    let mut break_minutes = 0;
+    let mut active_days = 0;
+    let mut best_day: Option<(NaiveDate, DailySummary)> = None;
+    for (date, sessions) in &days {
+        let day = DailySummary::from_sessions(sessions);
+        summary.merge(&day);
+        if day.complete_count + day.partial_count > 0 {
+            active_days += 1;
+        }
+        break_minutes += sessions
+            .iter()
+            .filter(|s| s.session_type == SessionType::Break)
+            .map(|s| s.duration_seconds / 60)
+            .sum::<u32>();
+        if day.total_focus_minutes > 0
+            && best_day
+                .as_ref()
+                .map(|(_, best)| day.total_focus_minutes > best.total_focus_minutes)
+                .unwrap_or(true)
+        {
+            best_day = Some((*date, day));
+        }
+    }
+
+    let session_count = summary.complete_count + summary.partial_count;
+    let period_days = (period.range.end - period.range.start).num_days() + 1;
+
+    let mut content = String::new();
+    content.push_str(&format!("# {} {}\n\n", period.kind.title(), period.key()));
+    content.push_str(&format!(
+        "{} to {}\n\n",
+        period.range.start.format("%Y-%m-%d"),
+        period.range.end.format("%Y-%m-%d")
+    ));
+
+    content.push_str("## Totals\n");
+    match (summary.complete_count * 100).checked_div(session_count) {
+        Some(completion_rate) => {
+            content.push_str(&summary.to_markdown());
+            content.push('\n');
+            content.push_str(&format!("- Completion rate: {}%\n", completion_rate));
+        }
+        None => content.push_str("*No focus sessions recorded*\n"),
+    }
+    content.push_str(&format!("- Breaks: {}m\n", break_minutes));
+    content.push_str(&format!(
+        "- Active days: {} of {}\n",
+        active_days, period_days
+    ));
+
+    content.push_str("\n## Best Day\n");
+    match &best_day {
+        Some((date, day)) => {
+            let count = day.complete_count + day.partial_count;
+            content.push_str(&format!(
+                "- {}: {}m ({} session{})\n",
+                date.format("%Y-%m-%d"),
+                day.total_focus_minutes,
+                count,
+                plural(count)
+            ));
+        }
+        None => content.push_str("*No focus sessions recorded*\n"),
+    }
+
+    let streak = streaks.current_length();
+    let longest = streaks.longest.map(|run| run.length).unwrap_or(0);
+    content.push_str("\n## Streak\n");
+    content.push_str(&format!(
+        "- Streak on {}: {} day{}\n",
+        period.range.end.format("%Y-%m-%d"),
+        streak,
+        plural(streak)
+    ));
+    content.push_str(&format!(
+        "- Longest streak so far: {} day{}\n",
+        longest,
+        plural(longest)
+    ));
+
+    content.push_str("\n## Achievements Unlocked\n");
+    let all = get_all_achievements();
+    let mut unlocked: Vec<(NaiveDate, String)> = achievements
+        .unlocked_achievements
+        .iter()
+        .filter_map(|record| {
+            let date = unlocked_on(&record.unlocked_at)?;
+            if !period.range.contains(date) {
+                return None;
+            }
+            let title = all
+                .iter()
+                .find(|a| a.id == record.id)
+                .map(|a| a.title.clone())
+                .unwrap_or_else(|| record.id.clone());
+            Some((date, title))
+        })
+        .collect();
+    unlocked.sort();
+    if unlocked.is_empty() {
+        content.push_str("*None this period*\n");
+    } else {
+        for (date, title) in unlocked {
+            content.push_str(&format!("- {} ({})\n", title, date.format("%Y-%m-%d")));
+        }
+    }
+
+    content
+}
+
+/// Reports folder, next to the effective sessions directory
+pub fn get_reports_directory() -> Result<PathBuf, AppError> {
+    let sessions_dir = get_sessions_directory()?;
+    let base = sessions_dir.parent().ok_or_else(|| {
+        AppError::StorageError(format!("Invalid sessions directory {:?}", sessions_dir))
+    })?;
+    Ok(base.join("reports"))
+}
+
+/// Write the report of a period, overwriting any previous version
+pub fn write_report(period: &ReportPeriod) -> Result<PathBuf, AppError> {
+    let dir = get_reports_directory()?;
+    fs::create_dir_all(&dir).map_err(|e| {
+        AppError::StorageError(format!("Failed to create reports directory: {}", e))
+    })?;
+
+    let days = load_sessions_in_range(&period.range)?;
+    let achievements = load_achievements()?;
+    let streaks = streaks_as_of(period.range.end)?;
+    let content = render_report(period, &days, &achievements, &streaks);
+
+    let path = dir.join(format!("{}.md", period.key()));
+    atomic_write(&path, &content)?;
+    Ok(path)
+}
+
+/// Write the reports of the last finished week and month if they are missing.
+/// Periods without any session are skipped.
+pub fn generate_due_reports() -> Result<Vec<PathBuf>, AppError> {
+    let settings = load_settings()?;
+    let today = settings.logical_date(Local::now().naive_local());
+    let dir = get_reports_directory()?;
+    let mut written = Vec::new();
+
+    for kind in [ReportKind::Weekly, ReportKind::Monthly] {
+        let period = ReportPeriod::previous(kind, today, settings.week_start);
+        if dir.join(format!("{}.md", period.key())).exists() {
+            continue;
+        }
+        if load_sessions_in_range(&period.range)?.is_empty() {
+            continue;
+        }
+        written.push(write_report(&period)?);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::achievements::{StreakRun, UnlockedAchievement};
+    use crate::storage::sessions::{test_session, SessionStatus};
+    use chrono::{Datelike, TimeZone};
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_report_periods() {
+        // 2026-10-12 is a Monday
+        let week = ReportPeriod::previous(ReportKind::Weekly, date(10, 12), WeekStart::Monday);
+        assert_eq!(week.key(), "2026-W41");
+        assert_eq!(week.range.start, date(10, 5));
+        assert_eq!(week.range.end, date(10, 11));
+
+        let month = ReportPeriod::previous(
+            ReportKind::Monthly,
+            date(1, 15).with_year(2027).unwrap(),
+            WeekStart::Monday,
+        );
+        assert_eq!(month.key(), "2026-12");
+        assert_eq!(month.range.end, date(12, 31));
+    }
+
+    #[test]
+    fn test_weekly_report_follows_week_start() {
+        // 2026-10-12 is a Monday, so the last finished Sunday week is 10-04 to 10-10
+        let week = ReportPeriod::previous(ReportKind::Weekly, date(10, 12), WeekStart::Sunday);
+
+        assert_eq!(week.range.start, date(10, 4));
+        assert_eq!(week.range.end, date(10, 10));
+        assert_eq!(week.key(), "2026-W41");
+    }
+
+    #[test]
+    fn test_render_weekly_report() {
+        let period = ReportPeriod::containing(ReportKind::Weekly, date(10, 7), WeekStart::Monday);
+        let days = vec![
+            (
+                date(10, 5),
+                vec![
+                    test_session(
+                        date(10, 5),
+                        (9, 0),
+                        25,
+                        SessionStatus::Complete,
+                        SessionType::Focus,
+                    ),
+                    test_session(
+                        date(10, 5),
+                        (9, 0),
+                        5,
+                        SessionStatus::Complete,
+                        SessionType::Break,
+                    ),
+                ],
+            ),
+            (
+                date(10, 7),
+                vec![
+                    test_session(
+                        date(10, 7),
+                        (9, 0),
+                        25,
+                        SessionStatus::Complete,
+                        SessionType::Focus,
+                    ),
+                    test_session(
+                        date(10, 7),
+                        (10, 0),
+                        25,
+                        SessionStatus::Complete,
+                        SessionType::Focus,
+                    ),
+                    test_session(
+                        date(10, 7),
+                        (11, 0),
+                        10,
+                        SessionStatus::Interrupted,
+                        SessionType::Focus,
+                    ),
+                ],
+            ),
+            // Outside of the week
+            (
+                date(10, 12),
+                vec![test_session(
+                    date(10, 12),
+                    (9, 0),
+                    25,
+                    SessionStatus::Complete,
+                    SessionType::Focus,
+                )],
+            ),
+        ];
+        let achievements = AchievementsData {
+            unlocked_achievements: vec![
+                UnlockedAchievement {
+                    id: "first_session".to_string(),
+                    unlocked_at: Local
+                        .with_ymd_and_hms(2026, 10, 5, 9, 25, 0)
+                        .single()
+                        .unwrap()
+                        .to_rfc3339(),
+                },
+                UnlockedAchievement {
+                    id: "sessions_10".to_string(),
+                    unlocked_at: "2026-11-01T10:00:00Z".to_string(),
+                },
+            ],
+            ..Default::default()
+        };
+
+        let run = |start: u32, end: u32| StreakRun {
+            start: date(10, start),
+            end: date(10, end),
+            length: end - start + 1,
+        };
+        let streaks = Streaks {
+            runs: vec![run(1, 5), run(9, 11)],
+            current: Some(run(9, 11)),
+            longest: Some(run(1, 5)),
+        };
+
+        let report = render_report(&period, &days, &achievements, &streaks);
+
+        assert!(report.starts_with("# Weekly Review 2026-W41\n\n2026-10-05 to 2026-10-11\n"));
+        assert!(report.contains("- Complete: 3 sessions (75m)\n"));
+        assert!(report.contains("- Partial: 1 session (10m)\n"));
+        assert!(report.contains("- Total focus: 85m\n"));
+        assert!(report.contains("- Completion rate: 75%\n"));
+        assert!(report.contains("- Breaks: 5m\n"));
+        assert!(report.contains("- Active days: 2 of 7\n"));
+        assert!(report.contains("## Best Day\n- 2026-10-07: 60m (3 sessions)\n"));
+        assert!(report.contains("- Streak on 2026-10-11: 3 days\n"));
+        assert!(report.contains("- Longest streak so far: 5 days\n"));
+        assert!(report.contains("- First Focus (2026-10-05)\n"));
+        assert!(!report.contains("Getting Started"));
+    }
+
+    #[test]
+    fn test_render_empty_report() {
+        let period = ReportPeriod::containing(ReportKind::Monthly, date(2, 10), WeekStart::Monday);
+
+        let report =
+            render_report(&period, &[], &AchievementsData::default(), &Streaks::default());
+
+        assert!(report.starts_with("# Monthly Review 2026-02\n\n2026-02-01 to 2026-02-28\n"));
+        assert!(report.contains("- Active days: 0 of 28\n"));
+        assert!(report.contains("*None this period*"));
+    }
+}
@@ -5,11 +5,13 @@
 
 use chrono::{DateTime, Local, NaiveDate, TimeZone};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fs::{self, File};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::error::AppError;
+use crate::storage::lock::lock_storage;
 
 /// Session completion status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -36,7 +38,7 @@ impl SessionStatus {
 }
 
 /// Type of session (focus or break)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SessionType {
     Focus,
@@ -61,6 +63,12 @@ pub struct Session {
     pub duration_seconds: u32,
     pub status: SessionStatus,
     pub session_type: SessionType,
+    /// Number of times the timer was paused during the session
+    #[serde(default)]
+    pub pauses: u32,
+    /// Integrity chain hash, only set for sessions recorded by the backend timer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain_hash: Option<String>,
 }
 
 impl Session {
@@ -78,17 +86,55 @@ impl Session {
             duration_seconds,
             status,
             session_type,
+            pauses: 0,
+            chain_hash: None,
         }
     }
 
-    /// Format session as markdown line
-    pub fn to_markdown_line(&self) -> String {
+    /// Whether the session carries an integrity chain hash
+    pub fn is_verified(&self) -> bool {
+        self.chain_hash.is_some()
+    }
+
+    /// Identity of a session as stored in the day files (start minute + type).
+    /// Day files only keep minute precision, so records sharing a key are the same session.
+    pub fn identity(&self) -> (String, SessionType) {
+        (
+            self.start_time.format("%Y-%m-%d %H:%M").to_string(),
+            self.session_type,
+        )
+    }
+
+    /// Format session as markdown line for the file of `date`, without the integrity tag.
+    /// This is the content covered by the chain hash. Sessions started after midnight
+    /// but filed under the previous day (see `Settings::day_starts_at`) are tagged `[next-day]`.
+    pub fn to_markdown_content(&self, date: NaiveDate) -> String {
         let start = self.start_time.format("%H:%M");
         let end = self.end_time.format("%H:%M");
         let marker = self.status.marker();
         let status = self.status.as_str();
         let minutes = self.duration_seconds / 60;
-        format!("- {} - {} {} {} ({}m)", start, end, marker, status, minutes)
+        let mut line = format!("- {} - {} {} {} ({}m)", start, end, marker, status, minutes);
+        // Focus is the default when parsing, so only breaks are tagged
+        if self.session_type == SessionType::Break {
+            line.push_str(" [break]");
+        }
+        if self.pauses > 0 {
+            line.push_str(&format!(" [paused:{}]", self.pauses));
+        }
+        if self.start_time.date_naive() > date {
+            line.push_str(" [next-day]");
+        }
+        line
+    }
+
+    /// Format session as markdown line for the file of `date`
+    pub fn to_markdown_line(&self, date: NaiveDate) -> String {
+        let mut line = self.to_markdown_content(date);
+        if let Some(hash) = &self.chain_hash {
+            line.push_str(&format!(" [verified:{}]", hash));
+        }
+        line
     }
 }
 
@@ -131,6 +177,15 @@ impl DailySummary {
         summary
     }
 
+    /// Add another day's summary to this one
+    pub fn merge(&mut self, other: &DailySummary) {
+        self.complete_count += other.complete_count;
+        self.complete_minutes += other.complete_minutes;
+        self.partial_count += other.partial_count;
+        self.partial_minutes += other.partial_minutes;
+        self.total_focus_minutes += other.total_focus_minutes;
+    }
+
     /// Format summary as markdown
     pub fn to_markdown(&self) -> String {
         let mut lines = Vec::new();
@@ -165,6 +220,8 @@ pub struct DailySessionFile {
     pub date: NaiveDate,
     pub sessions: Vec<Session>,
     pub summary: DailySummary,
+    /// Keyed tag of the last session sealed into the day's integrity chain
+    pub chain_tip: Option<String>,
 }
 
 impl DailySessionFile {
@@ -174,6 +231,7 @@ impl DailySessionFile {
             date,
             sessions: Vec::new(),
             summary: DailySummary::default(),
+            chain_tip: None,
         }
     }
 
@@ -189,6 +247,9 @@ impl DailySessionFile {
         
         // Header
         content.push_str(&format!("# {}\n\n", self.date.format("%Y-%m-%d")));
+        if let Some(tip) = &self.chain_tip {
+            content.push_str(&format!("<!-- verified-tip:{} -->\n\n", tip));
+        }
         
         // Sessions section
         content.push_str("## Sessions\n");
@@ -196,7 +257,7 @@ impl DailySessionFile {
             content.push_str("*No sessions recorded*\n");
         } else {
             for session in &self.sessions {
-                content.push_str(&session.to_markdown_line());
+                content.push_str(&session.to_markdown_line(self.date));
                 content.push('\n');
             }
         }
@@ -222,6 +283,14 @@ impl DailySessionFile {
         
         for line in content.lines() {
             let line = line.trim();
+
+            if let Some(tip) = line
+                .strip_prefix("<!-- verified-tip:")
+                .and_then(|rest| rest.strip_suffix("-->"))
+            {
+                file.chain_tip = Some(tip.trim().to_string());
+                continue;
+            }
             
             // Track sections
             if line.starts_with("## Sessions") {
@@ -263,6 +332,13 @@ fn parse_session_line(line: &str, date: NaiveDate) -> Option<Session> {
     let end_str = &rest[0..5];
     let end_time_naive = chrono::NaiveTime::parse_from_str(end_str, "%H:%M").ok()?;
     
+    // Parse type (untagged lines are focus sessions)
+    let session_type = if rest.contains("[break]") {
+        SessionType::Break
+    } else {
+        SessionType::Focus
+    };
+
     // Parse status
     let status = if rest.contains("✓") || rest.contains("Complete") {
         SessionStatus::Complete
@@ -271,9 +347,9 @@ fn parse_session_line(line: &str, date: NaiveDate) -> Option<Session> {
     };
     
     // Parse duration from (XXm)
-    let duration_seconds = if let Some(start) = rest.rfind('(') {
-        if let Some(end) = rest.rfind('m') {
-            let minutes_str = &rest[start + 1..end];
+    let duration_seconds = if let Some(start) = rest.find('(') {
+        if let Some(end) = rest[start..].find("m)") {
+            let minutes_str = &rest[start + 1..start + end];
             minutes_str.parse::<u32>().unwrap_or(0) * 60
         } else {
             0
@@ -281,10 +357,38 @@ fn parse_session_line(line: &str, date: NaiveDate) -> Option<Session> {
     } else {
         0
     };
+
+    // Parse pause count from [paused:N]
+    let pauses = rest
+        .find("[paused:")
+        .and_then(|start| {
+            let tag = &rest[start + "[paused:".len()..];
+            tag.find(']').and_then(|end| tag[..end].parse::<u32>().ok())
+        })
+        .unwrap_or(0);
+
+    // Parse integrity chain hash from [verified:HASH]
+    let chain_hash = rest.find("[verified:").and_then(|start| {
+        let tag = &rest[start + "[verified:".len()..];
+        tag.find(']').map(|end| tag[..end].to_string())
+    });
     
+    // Sessions past midnight filed under the previous day start on the next calendar day,
+    // and sessions running past midnight end on the day after they started
+    let start_date = if rest.contains("[next-day]") {
+        date.succ_opt()?
+    } else {
+        date
+    };
+    let end_date = if end_time_naive < start_time_naive {
+        start_date.succ_opt()?
+    } else {
+        start_date
+    };
+
     // Construct DateTime<Local>
-    let start_datetime = date.and_time(start_time_naive);
-    let end_datetime = date.and_time(end_time_naive);
+    let start_datetime = start_date.and_time(start_time_naive);
+    let end_datetime = end_date.and_time(end_time_naive);
     
     // Convert to Local timezone
     let start_time = Local::now()
@@ -301,7 +405,9 @@ fn parse_session_line(line: &str, date: NaiveDate) -> Option<Session> {
         end_time,
         duration_seconds,
         status,
-        session_type: SessionType::Focus, // Default to focus when parsing
+        session_type,
+        pauses,
+        chain_hash,
     })
 }
 
@@ -320,20 +426,32 @@ pub fn get_session_file_path(date: NaiveDate) -> Result<PathBuf, AppError> {
     Ok(dir.join(filename))
 }
 
+/// Load the daily file for a date, or an empty one if it doesn't exist yet
+pub(crate) fn load_daily_file(date: NaiveDate, file_path: &PathBuf) -> Result<DailySessionFile, AppError> {
+    if file_path.exists() {
+        let content = fs::read_to_string(file_path).map_err(|e| {
+            AppError::StorageError(format!("Failed to read session file: {}", e))
+        })?;
+        DailySessionFile::from_markdown(&content, date)
+    } else {
+        Ok(DailySessionFile::new(date))
+    }
+}
+
+/// Day file a session belongs to, under the configured day boundary
+pub fn session_day(session: &Session) -> Result<NaiveDate, AppError> {
+    let settings = crate::storage::settings::load_settings()?;
+    Ok(settings.logical_date(session.start_time.naive_local()))
+}
+
 /// Save a session to the appropriate daily file (atomic write)
 pub fn save_session(session: Session) -> Result<DailySessionFile, AppError> {
-    let date = session.start_time.date_naive();
+    let _lock = lock_storage();
+    let date = session_day(&session)?;
     let file_path = get_session_file_path(date)?;
     
     // Load existing file or create new one
-    let mut daily_file = if file_path.exists() {
-        let content = fs::read_to_string(&file_path).map_err(|e| {
-            AppError::StorageError(format!("Failed to read session file: {}", e))
-        })?;
-        DailySessionFile::from_markdown(&content, date)?
-    } else {
-        DailySessionFile::new(date)
-    };
+    let mut daily_file = load_daily_file(date, &file_path)?;
     
     // Add session and recalculate summary
     daily_file.add_session(session);
@@ -344,8 +462,53 @@ pub fn save_session(session: Session) -> Result<DailySessionFile, AppError> {
     Ok(daily_file)
 }
 
+/// Save a session recorded by the backend timer, sealing it into the day's integrity chain
+pub fn save_verified_session(session: Session) -> Result<DailySessionFile, AppError> {
+    let _lock = lock_storage();
+    let date = session_day(&session)?;
+    let file_path = get_session_file_path(date)?;
+
+    let mut daily_file = load_daily_file(date, &file_path)?;
+    let key = crate::storage::integrity::load_or_create_integrity_key()?;
+    crate::storage::integrity::append_sealed(&key, &mut daily_file, session);
+
+    atomic_write(&file_path, &daily_file.to_markdown())?;
+
+    Ok(daily_file)
+}
+
+/// Merge sessions into a daily file, skipping those already present (by identity).
+///
+/// Sessions are kept in chronological order, so records added after the fact
+/// (imports, merges) land in the right place. Returns the sessions actually added;
+/// the file is only rewritten when it changes.
+pub fn merge_sessions_for_date(
+    date: NaiveDate,
+    sessions: Vec<Session>,
+) -> Result<Vec<Session>, AppError> {
+    let _lock = lock_storage();
+    let file_path = get_session_file_path(date)?;
+    let mut daily_file = load_daily_file(date, &file_path)?;
+
+    let mut seen: HashSet<(String, SessionType)> =
+        daily_file.sessions.iter().map(Session::identity).collect();
+    let added: Vec<Session> = sessions
+        .into_iter()
+        .filter(|session| seen.insert(session.identity()))
+        .collect();
+
+    if !added.is_empty() {
+        daily_file.sessions.extend(added.iter().cloned());
+        daily_file.sessions.sort_by_key(|s| s.start_time);
+        daily_file.summary = DailySummary::from_sessions(&daily_file.sessions);
+        atomic_write(&file_path, &daily_file.to_markdown())?;
+    }
+
+    Ok(added)
+}
+
 /// Write content to file atomically (write to temp, then rename)
-fn atomic_write(path: &PathBuf, content: &str) -> Result<(), AppError> {
+pub(crate) fn atomic_write(path: &PathBuf, content: &str) -> Result<(), AppError> {
     let temp_path = path.with_extension("tmp");
     
     // Write to temp file
@@ -388,9 +551,145 @@ pub fn load_sessions_for_date(date: NaiveDate) -> Result<Vec<Session>, AppError>
     Ok(daily_file.sessions)
 }
 
+/// Inclusive range of calendar dates, as sent by the frontend (`{ start, end }`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DateRange {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl DateRange {
+    /// Check whether a date falls inside the range (both ends inclusive)
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        date >= self.start && date <= self.end
+    }
+
+    /// Reject ranges whose end is before their start
+    pub fn validate(&self) -> Result<(), AppError> {
+        if self.end < self.start {
+            return Err(AppError::ParseError(format!(
+                "Invalid date range: {} is after {}",
+                self.start, self.end
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// List the dates that have a session file, sorted ascending
+///
+/// Only files named `YYYY-MM-DD.md` are considered; anything else in the
+/// sessions directory is ignored.
+pub fn list_session_dates() -> Result<Vec<NaiveDate>, AppError> {
+    list_session_dates_in(&get_sessions_directory()?)
+}
+
+/// Same as [`list_session_dates`], for an explicit sessions directory
+pub fn list_session_dates_in(dir: &Path) -> Result<Vec<NaiveDate>, AppError> {
+    let entries = fs::read_dir(dir).map_err(|e| {
+        AppError::StorageError(format!("Failed to read sessions directory: {}", e))
+    })?;
+
+    let mut dates: Vec<NaiveDate> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension().map(|e| e == "md").unwrap_or(false) {
+                let stem = path.file_stem()?.to_str()?;
+                NaiveDate::parse_from_str(stem, "%Y-%m-%d").ok()
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    dates.sort();
+    Ok(dates)
+}
+
+/// Load all sessions recorded within a date range, grouped by day (ascending)
+///
+/// Days without a session file are skipped.
+pub fn load_sessions_in_range(range: &DateRange) -> Result<Vec<(NaiveDate, Vec<Session>)>, AppError> {
+    range.validate()?;
+
+    let mut days = Vec::new();
+    for date in list_session_dates()? {
+        if !range.contains(date) {
+            continue;
+        }
+        let sessions = load_sessions_for_date(date)?;
+        if !sessions.is_empty() {
+            days.push((date, sessions));
+        }
+    }
+
+    Ok(days)
+}
+
+/// One page of session history, most recent day first
+#[derive(Debug)]
+pub struct HistoryPage {
+    pub days: Vec<(NaiveDate, Vec<Session>)>,
+    /// Whether older days with sessions exist beyond this page
+    pub has_more: bool,
+    /// Number of days with at least one session across the whole history
+    pub total_days: u32,
+}
+
+/// Build a history page from the sorted list of session file dates, each with its
+/// number of sessions. Only dates strictly before `cursor` are considered and empty
+/// days are skipped.
+fn build_history_page<F>(
+    dates: &[(NaiveDate, u32)],
+    cursor: Option<NaiveDate>,
+    limit: usize,
+    mut load: F,
+) -> Result<HistoryPage, AppError>
+where
+    F: FnMut(NaiveDate) -> Result<Vec<Session>, AppError>,
+{
+    let non_empty: Vec<NaiveDate> = dates
+        .iter()
+        .filter(|(_, session_count)| *session_count > 0)
+        .map(|(date, _)| *date)
+        .collect();
+
+    let mut candidates = non_empty
+        .iter()
+        .rev()
+        .filter(|date| cursor.map(|c| **date < c).unwrap_or(true));
+
+    let mut days = Vec::new();
+    for date in candidates.by_ref().take(limit) {
+        days.push((*date, load(*date)?));
+    }
+
+    Ok(HistoryPage {
+        days,
+        has_more: candidates.next().is_some(),
+        total_days: non_empty.len() as u32,
+    })
+}
+
+/// Load the next `limit` days with sessions before `cursor` (the most recent day
+/// with sessions first when `None`). Day files are counted from the summary cache.
+pub fn load_history_page(cursor: Option<NaiveDate>, limit: usize) -> Result<HistoryPage, AppError> {
+    let all = DateRange {
+        start: NaiveDate::MIN,
+        end: NaiveDate::MAX,
+    };
+    let dates: Vec<(NaiveDate, u32)> = crate::storage::stats::load_day_digests(&all)?
+        .into_iter()
+        .map(|(date, digest)| (date, digest.session_count))
+        .collect();
+    build_history_page(&dates, cursor, limit, load_sessions_for_date)
+}
+
 /// Load today's sessions
 pub fn load_today_sessions() -> Result<Vec<Session>, AppError> {
-    let today = Local::now().date_naive();
+    let today = crate::storage::settings::logical_today()?;
     load_sessions_for_date(today)
 }
 
@@ -402,14 +701,33 @@ pub fn get_daily_summary(date: NaiveDate) -> Result<DailySummary, AppError> {
 
 /// Get today's summary
 pub fn get_today_summary() -> Result<DailySummary, AppError> {
-    let today = Local::now().date_naive();
+    let today = crate::storage::settings::logical_today()?;
     get_daily_summary(today)
 }
 
+/// Test fixture shared by the storage modules: a session starting on `date` at
+/// `(hour, minute)` local time and lasting `minutes`
+#[cfg(test)]
+pub(crate) fn test_session(
+    date: NaiveDate,
+    (hour, minute): (u32, u32),
+    minutes: i64,
+    status: SessionStatus,
+    session_type: SessionType,
+) -> Session {
+    use chrono::{Datelike, TimeZone};
+
+    let start = Local
+        .with_ymd_and_hms(date.year(), date.month(), date.day(), hour, minute, 0)
+        .single()
+        .unwrap();
+    Session::new(start, start + chrono::Duration::minutes(minutes), status, session_type)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::{Datelike, TimeZone};
+    use chrono::TimeZone;
 
     fn create_test_session(
         hour: u32,
@@ -417,26 +735,19 @@ mod tests {
         duration_minutes: u32,
         status: SessionStatus,
     ) -> Session {
-        let date = Local::now().date_naive();
-        let start = Local
-            .with_ymd_and_hms(date.year(), date.month(), date.day(), hour, minute, 0)
-            .single()
-            .unwrap();
-        let end = start + chrono::Duration::minutes(duration_minutes as i64);
-        
-        Session {
-            start_time: start,
-            end_time: end,
-            duration_seconds: duration_minutes * 60,
+        test_session(
+            Local::now().date_naive(),
+            (hour, minute),
+            duration_minutes as i64,
             status,
-            session_type: SessionType::Focus,
-        }
+            SessionType::Focus,
+        )
     }
 
     #[test]
     fn test_session_to_markdown_line() {
         let session = create_test_session(9, 15, 25, SessionStatus::Complete);
-        let line = session.to_markdown_line();
+        let line = session.to_markdown_line(Local::now().date_naive());
         
         assert!(line.contains("09:15"));
         assert!(line.contains("✓"));
@@ -447,7 +758,7 @@ mod tests {
     #[test]
     fn test_session_to_markdown_line_interrupted() {
         let session = create_test_session(10, 0, 14, SessionStatus::Interrupted);
-        let line = session.to_markdown_line();
+        let line = session.to_markdown_line(Local::now().date_naive());
         
         assert!(line.contains("10:00"));
         assert!(line.contains("○"));
@@ -518,6 +829,80 @@ mod tests {
         assert_eq!(session.duration_seconds, 25 * 60);
     }
 
+    #[test]
+    fn test_break_session_type_round_trip() {
+        let today = Local::now().date_naive();
+        let mut break_session = create_test_session(9, 40, 5, SessionStatus::Complete);
+        break_session.session_type = SessionType::Break;
+
+        let line = break_session.to_markdown_line(today);
+        assert!(line.ends_with("(5m) [break]"));
+
+        let parsed = parse_session_line(&line, today).unwrap();
+        assert_eq!(parsed.session_type, SessionType::Break);
+        assert_eq!(parsed.duration_seconds, 5 * 60);
+
+        let focus = parse_session_line("- 09:15 - 09:40 ✓ Complete (25m)", today).unwrap();
+        assert_eq!(focus.session_type, SessionType::Focus);
+    }
+
+    #[test]
+    fn test_verified_session_round_trip() {
+        let today = Local::now().date_naive();
+        let mut session = create_test_session(9, 15, 25, SessionStatus::Complete);
+        session.chain_hash = Some("0123456789abcdef".to_string());
+
+        let line = session.to_markdown_line(today);
+        assert!(line.ends_with("(25m) [verified:0123456789abcdef]"));
+        assert_eq!(session.to_markdown_content(today), "- 09:15 - 09:40 ✓ Complete (25m)");
+
+        let parsed = parse_session_line(&line, today).unwrap();
+        assert_eq!(parsed.chain_hash, Some("0123456789abcdef".to_string()));
+        assert_eq!(parsed.duration_seconds, 25 * 60);
+        assert!(parsed.is_verified());
+    }
+
+    #[test]
+    fn test_pause_count_round_trip() {
+        let today = Local::now().date_naive();
+        let mut session = create_test_session(9, 15, 12, SessionStatus::Interrupted);
+        session.pauses = 2;
+        session.chain_hash = Some("0123456789abcdef".to_string());
+
+        let line = session.to_markdown_line(today);
+        assert_eq!(
+            line,
+            "- 09:15 - 09:27 ○ Interrupted (12m) [paused:2] [verified:0123456789abcdef]"
+        );
+
+        let parsed = parse_session_line(&line, today).unwrap();
+        assert_eq!(parsed.pauses, 2);
+        assert_eq!(parsed.duration_seconds, 12 * 60);
+        assert_eq!(parsed.chain_hash, Some("0123456789abcdef".to_string()));
+    }
+
+    #[test]
+    fn test_sessions_past_midnight_round_trip() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+        let at = |d: u32, h: u32, m: u32| {
+            Local.with_ymd_and_hms(2026, 10, d, h, m, 0).single().unwrap()
+        };
+        let focus = |start, end| Session::new(start, end, SessionStatus::Complete, SessionType::Focus);
+        let mut file = DailySessionFile::new(date);
+        // Runs past midnight, then a session filed under the previous day
+        file.add_session(focus(at(19, 23, 50), at(20, 0, 15)));
+        file.add_session(focus(at(20, 1, 0), at(20, 1, 25)));
+
+        let markdown = file.to_markdown();
+        assert!(markdown.contains("- 23:50 - 00:15 ✓ Complete (25m)\n"));
+        assert!(markdown.contains("- 01:00 - 01:25 ✓ Complete (25m) [next-day]"));
+
+        let parsed = DailySessionFile::from_markdown(&markdown, date).unwrap();
+        assert_eq!(parsed.sessions[0].end_time, at(20, 0, 15));
+        assert_eq!(parsed.sessions[1].start_time, at(20, 1, 0));
+        assert_eq!(parsed.sessions[1].end_time, at(20, 1, 25));
+    }
+
     #[test]
     fn test_daily_file_round_trip() {
         let today = Local::now().date_naive();
@@ -570,6 +955,8 @@ mod tests {
             duration_seconds: 300,
             status: SessionStatus::Complete,
             session_type: SessionType::Break,
+            pauses: 0,
+            chain_hash: None,
         };
         
         let mut daily_file = DailySessionFile::new(today);
@@ -579,4 +966,58 @@ mod tests {
         assert_eq!(daily_file.summary.complete_count, 0);
         assert_eq!(daily_file.summary.total_focus_minutes, 0);
     }
+
+    #[test]
+    fn test_date_range_contains_is_inclusive() {
+        let range = DateRange {
+            start: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            end: NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+        };
+
+        assert!(range.contains(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap()));
+        assert!(range.contains(NaiveDate::from_ymd_opt(2026, 1, 31).unwrap()));
+        assert!(!range.contains(NaiveDate::from_ymd_opt(2026, 2, 1).unwrap()));
+        assert!(range.validate().is_ok());
+    }
+
+    #[test]
+    fn test_date_range_rejects_reversed_range() {
+        let range = DateRange {
+            start: NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(),
+            end: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+        };
+
+        assert!(range.validate().is_err());
+    }
+
+    #[test]
+    fn test_date_range_deserialization() {
+        let range: DateRange =
+            serde_json::from_str(r#"{"start": "2026-01-01", "end": "2026-01-07"}"#).unwrap();
+
+        assert_eq!(range.start, NaiveDate::from_ymd_opt(2026, 1, 1).unwrap());
+        assert_eq!(range.end, NaiveDate::from_ymd_opt(2026, 1, 7).unwrap());
+    }
+
+    #[test]
+    fn test_history_page_skips_empty_days_and_follows_cursor() {
+        let date = |d| NaiveDate::from_ymd_opt(2026, 1, d).unwrap();
+        // The file of the 8th exists but holds no sessions
+        let dates = vec![(date(1), 1), (date(3), 1), (date(4), 1), (date(8), 0), (date(9), 1)];
+        let load = |d: NaiveDate| -> Result<Vec<Session>, AppError> {
+            assert_ne!(d, date(8));
+            Ok(vec![create_test_session(9, 0, 25, SessionStatus::Complete)])
+        };
+
+        let first = build_history_page(&dates, None, 2, load).unwrap();
+        let first_dates: Vec<NaiveDate> = first.days.iter().map(|(d, _)| *d).collect();
+        assert_eq!(first_dates, vec![date(9), date(4)]);
+        assert!(first.has_more);
+        assert_eq!(first.total_days, 4);
+
+        let second = build_history_page(&dates, Some(date(4)), 2, load).unwrap();
+        let second_dates: Vec<NaiveDate> = second.days.iter().map(|(d, _)| *d).collect();
+        assert_eq!(second_dates, vec![date(3), date(1)]);
+        assert!(!second.has_more);
+    }
 }
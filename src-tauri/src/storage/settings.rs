@@ -3,12 +3,14 @@
 //! Handles reading and writing application settings to `settings.json`.
 //! Settings are stored in platform-specific app data directories.
 
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
 use serde::{Deserialize, Serialize};
 use std::fs::{self, File};
 use std::io::Write;
 use std::path::PathBuf;
 
 use crate::error::AppError;
+use crate::storage::integrity::INTEGRITY_KEY_FILE;
 
 /// Application settings matching AR8 specification
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -33,6 +35,318 @@ pub struct Settings {
     /// Selected character (cat, owl, etc.)
     #[serde(default = "default_character")]
     pub character: String,
+
+    /// Scheduled automatic backups
+    #[serde(default)]
+    pub auto_backup: AutoBackupSettings,
+
+    /// Only count timer-recorded (verified) sessions towards streaks and achievements
+    #[serde(default)]
+    pub count_only_verified_sessions: bool,
+
+    /// Daily and weekly focus goals
+    #[serde(default)]
+    pub goals: GoalSettings,
+
+    /// First day of the week for calendar-week views and weekly goals
+    #[serde(default)]
+    pub week_start: WeekStart,
+
+    /// Locale for day names, e.g. "fr_FR" (None = system locale)
+    #[serde(default)]
+    pub locale: Option<String>,
+
+    /// What keeps a streak alive
+    #[serde(default)]
+    pub streak: StreakRules,
+
+    /// Time at which a new day starts, e.g. "04:00" so late sessions count toward
+    /// the previous day (default: midnight)
+    #[serde(default)]
+    pub day_starts_at: NaiveTime,
+
+    /// Evening reminder when today's session is still missing from the streak
+    #[serde(default)]
+    pub streak_reminder: StreakReminderSettings,
+
+    /// How XP is earned and levels are reached
+    #[serde(default)]
+    pub progression: ProgressionRules,
+}
+
+impl Settings {
+    /// Day a local date and time belongs to under `day_starts_at`
+    pub fn logical_date(&self, at: NaiveDateTime) -> NaiveDate {
+        logical_date(at, self.day_starts_at)
+    }
+}
+
+/// Day a local date and time belongs to when days start at `day_starts_at`
+pub fn logical_date(at: NaiveDateTime, day_starts_at: NaiveTime) -> NaiveDate {
+    (at - day_starts_at.signed_duration_since(NaiveTime::MIN)).date()
+}
+
+/// Time elapsed between the start of the day, at `day_starts_at`, and `time`
+pub fn time_since_day_start(time: NaiveTime, day_starts_at: NaiveTime) -> Duration {
+    let offset = time.signed_duration_since(day_starts_at);
+    if offset < Duration::zero() {
+        offset + Duration::days(1)
+    } else {
+        offset
+    }
+}
+
+/// Today, under the configured day boundary
+pub fn logical_today() -> Result<NaiveDate, AppError> {
+    Ok(load_settings()?.logical_date(Local::now().naive_local()))
+}
+
+/// First day of a calendar week
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WeekStart {
+    /// ISO 8601 weeks
+    #[default]
+    Monday,
+    Sunday,
+}
+
+impl WeekStart {
+    /// Position of `date` in its week, 0 for the first day
+    pub fn day_of_week(&self, date: NaiveDate) -> u32 {
+        match self {
+            WeekStart::Monday => date.weekday().num_days_from_monday(),
+            WeekStart::Sunday => date.weekday().num_days_from_sunday(),
+        }
+    }
+
+    /// First day of the week containing `date`
+    pub fn week_start_of(&self, date: NaiveDate) -> NaiveDate {
+        date - Duration::days(self.day_of_week(date) as i64)
+    }
+}
+
+/// What a goal counts
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum GoalMetric {
+    /// Minutes of focus, complete and interrupted sessions alike
+    FocusMinutes,
+    /// Completed focus sessions
+    CompletedSessions,
+}
+
+impl GoalMetric {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalMetric::FocusMinutes => "focusMinutes",
+            GoalMetric::CompletedSessions => "completedSessions",
+        }
+    }
+}
+
+/// A target for one period
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Goal {
+    pub metric: GoalMetric,
+    pub target: u32,
+}
+
+/// Focus goals (None = no goal for that period)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GoalSettings {
+    #[serde(default)]
+    pub daily: Option<Goal>,
+
+    #[serde(default)]
+    pub weekly: Option<Goal>,
+}
+
+/// Rules deciding which days count toward a streak
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StreakRules {
+    /// Minimum a day must reach to extend the streak (default: 1 completed session)
+    #[serde(default = "default_streak_minimum")]
+    pub daily_minimum: Goal,
+
+    /// Weekdays that neither break nor extend the streak, e.g. `["Sat", "Sun"]`
+    #[serde(default)]
+    pub rest_days: Vec<Weekday>,
+
+    /// A streak freeze is earned for every this many streak days (0 = no freezes)
+    #[serde(default = "default_freeze_interval_days")]
+    pub freeze_interval_days: u32,
+
+    /// Maximum number of unspent streak freezes
+    #[serde(default = "default_max_freezes")]
+    pub max_freezes: u32,
+}
+
+impl StreakRules {
+    pub fn is_rest_day(&self, date: NaiveDate) -> bool {
+        self.rest_days.contains(&date.weekday())
+    }
+}
+
+impl Default for StreakRules {
+    fn default() -> Self {
+        Self {
+            daily_minimum: default_streak_minimum(),
+            rest_days: Vec::new(),
+            freeze_interval_days: default_freeze_interval_days(),
+            max_freezes: default_max_freezes(),
+        }
+    }
+}
+
+fn default_streak_minimum() -> Goal {
+    Goal {
+        metric: GoalMetric::CompletedSessions,
+        target: 1,
+    }
+}
+
+fn default_freeze_interval_days() -> u32 {
+    7
+}
+
+fn default_max_freezes() -> u32 {
+    2
+}
+
+/// XP awarded per unit of progress, and the XP curve of the levels
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressionRules {
+    /// XP per minute of focus, interrupted sessions included (default: 1)
+    #[serde(default = "default_xp_per_focus_minute")]
+    pub xp_per_focus_minute: u32,
+
+    /// XP per completed focus session (default: 10)
+    #[serde(default = "default_xp_per_session")]
+    pub xp_per_session: u32,
+
+    /// XP per day meeting the streak minimum (default: 5)
+    #[serde(default = "default_xp_per_streak_day")]
+    pub xp_per_streak_day: u32,
+
+    /// XP per unlocked achievement (default: 100)
+    #[serde(default = "default_xp_per_achievement")]
+    pub xp_per_achievement: u32,
+
+    /// XP needed to go from level 1 to 2. Each further level needs this much
+    /// more than the previous one (default: 100, so levels start at 0, 100, 300, 600...)
+    #[serde(default = "default_level_xp_step")]
+    pub level_xp_step: u32,
+}
+
+impl Default for ProgressionRules {
+    fn default() -> Self {
+        Self {
+            xp_per_focus_minute: default_xp_per_focus_minute(),
+            xp_per_session: default_xp_per_session(),
+            xp_per_streak_day: default_xp_per_streak_day(),
+            xp_per_achievement: default_xp_per_achievement(),
+            level_xp_step: default_level_xp_step(),
+        }
+    }
+}
+
+fn default_xp_per_focus_minute() -> u32 {
+    1
+}
+
+fn default_xp_per_session() -> u32 {
+    10
+}
+
+fn default_xp_per_streak_day() -> u32 {
+    5
+}
+
+fn default_xp_per_achievement() -> u32 {
+    100
+}
+
+fn default_level_xp_step() -> u32 {
+    100
+}
+
+/// Streak-at-risk reminder configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StreakReminderSettings {
+    /// Whether the reminder is sent
+    #[serde(default = "default_streak_reminder_enabled")]
+    pub enabled: bool,
+
+    /// Time of day after which the reminder is sent (default: 20:00)
+    #[serde(default = "default_streak_reminder_time")]
+    pub time: NaiveTime,
+
+    /// Period during which no reminder is sent (None = no quiet hours)
+    #[serde(default)]
+    pub quiet_hours: Option<QuietHours>,
+}
+
+/// A daily period from `start` to `end`, which may wrap past midnight
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct QuietHours {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl QuietHours {
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+impl Default for StreakReminderSettings {
+    fn default() -> Self {
+        Self {
+            enabled: default_streak_reminder_enabled(),
+            time: default_streak_reminder_time(),
+            quiet_hours: None,
+        }
+    }
+}
+
+fn default_streak_reminder_enabled() -> bool {
+    true
+}
+
+fn default_streak_reminder_time() -> NaiveTime {
+    NaiveTime::from_hms_opt(20, 0, 0).unwrap()
+}
+
+/// Scheduled backup configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoBackupSettings {
+    /// Whether automatic backups are enabled
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Folder that receives the backup archives (None = disabled)
+    #[serde(default)]
+    pub folder: Option<String>,
+
+    /// Days between two automatic backups (default: 7)
+    #[serde(default = "default_backup_interval_days")]
+    pub interval_days: u32,
+
+    /// Number of automatic backups to keep, older ones are deleted (default: 5)
+    #[serde(default = "default_backup_keep_count")]
+    pub keep_count: u32,
 }
 
 fn default_focus_duration() -> u32 {
@@ -51,6 +365,25 @@ fn default_character() -> String {
     "cat".to_string()
 }
 
+fn default_backup_interval_days() -> u32 {
+    7
+}
+
+fn default_backup_keep_count() -> u32 {
+    5
+}
+
+impl Default for AutoBackupSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            folder: None,
+            interval_days: default_backup_interval_days(),
+            keep_count: default_backup_keep_count(),
+        }
+    }
+}
+
 impl Default for Settings {
     fn default() -> Self {
         Self {
@@ -59,6 +392,15 @@ impl Default for Settings {
             storage_path: None,
             audio_enabled: default_audio_enabled(),
             character: default_character(),
+            auto_backup: AutoBackupSettings::default(),
+            count_only_verified_sessions: false,
+            goals: GoalSettings::default(),
+            week_start: WeekStart::default(),
+            locale: None,
+            streak: StreakRules::default(),
+            day_starts_at: NaiveTime::MIN,
+            streak_reminder: StreakReminderSettings::default(),
+            progression: ProgressionRules::default(),
         }
     }
 }
@@ -264,6 +606,16 @@ pub fn migrate_storage(old_path: &PathBuf, new_path: &PathBuf) -> Result<(), App
         }
     }
 
+    // Sessions sealed with the old key only verify if the key moves along. An
+    // existing key at the destination (synced from another machine) is kept.
+    let old_key = old_path.join(INTEGRITY_KEY_FILE);
+    let new_key = new_path.join(INTEGRITY_KEY_FILE);
+    if old_key.exists() && !new_key.exists() {
+        fs::copy(&old_key, &new_key).map_err(|e| {
+            AppError::StorageError(format!("Failed to copy integrity key: {}", e))
+        })?;
+    }
+
     Ok(())
 }
 
@@ -328,6 +680,31 @@ mod tests {
         assert!(settings.storage_path.is_none()); // default
         assert!(settings.audio_enabled); // default
         assert_eq!(settings.character, "cat"); // default
+        assert!(!settings.auto_backup.enabled); // default
+        assert_eq!(settings.auto_backup.interval_days, 7); // default
+        assert!(settings.goals.daily.is_none()); // default
+        assert_eq!(settings.week_start, WeekStart::Monday); // default
+        assert_eq!(settings.streak.daily_minimum.target, 1); // default
+        assert!(settings.streak.rest_days.is_empty()); // default
+        assert_eq!(settings.streak.freeze_interval_days, 7); // default
+        assert_eq!(settings.streak.max_freezes, 2); // default
+    }
+
+    #[test]
+    fn test_streak_rest_days_deserialization() {
+        let json = r#"{ "streak": { "restDays": ["Sat", "sunday"] } }"#;
+
+        let settings: Settings = serde_json::from_str(json).unwrap();
+
+        assert_eq!(settings.streak.rest_days, vec![Weekday::Sat, Weekday::Sun]);
+        assert_eq!(
+            settings.streak.daily_minimum.metric,
+            GoalMetric::CompletedSessions
+        );
+        // 2026-10-17 is a Saturday
+        assert!(settings
+            .streak
+            .is_rest_day(NaiveDate::from_ymd_opt(2026, 10, 17).unwrap()));
     }
 
     #[test]
@@ -338,6 +715,50 @@ mod tests {
             storage_path: Some("/my/path".to_string()),
             audio_enabled: false,
             character: "fox".to_string(),
+            auto_backup: AutoBackupSettings {
+                enabled: true,
+                folder: Some("/my/backups".to_string()),
+                interval_days: 1,
+                keep_count: 3,
+            },
+            count_only_verified_sessions: true,
+            goals: GoalSettings {
+                daily: Some(Goal {
+                    metric: GoalMetric::CompletedSessions,
+                    target: 4,
+                }),
+                weekly: Some(Goal {
+                    metric: GoalMetric::FocusMinutes,
+                    target: 600,
+                }),
+            },
+            week_start: WeekStart::Sunday,
+            locale: Some("fr_FR".to_string()),
+            streak: StreakRules {
+                daily_minimum: Goal {
+                    metric: GoalMetric::FocusMinutes,
+                    target: 50,
+                },
+                rest_days: vec![Weekday::Sat, Weekday::Sun],
+                freeze_interval_days: 10,
+                max_freezes: 1,
+            },
+            day_starts_at: NaiveTime::from_hms_opt(4, 0, 0).unwrap(),
+            streak_reminder: StreakReminderSettings {
+                enabled: false,
+                time: NaiveTime::from_hms_opt(21, 30, 0).unwrap(),
+                quiet_hours: Some(QuietHours {
+                    start: NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+                    end: NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+                }),
+            },
+            progression: ProgressionRules {
+                xp_per_focus_minute: 2,
+                xp_per_session: 20,
+                xp_per_streak_day: 0,
+                xp_per_achievement: 250,
+                level_xp_step: 500,
+            },
         };
 
         let json = serde_json::to_string(&original).unwrap();
@@ -393,6 +814,7 @@ mod tests {
 
         fs::write(old_sessions.join("2024-01-01.md"), "# Test").unwrap();
         fs::write(old_sessions.join("2024-01-02.md"), "# Test 2").unwrap();
+        fs::write(old_dir.path().join(INTEGRITY_KEY_FILE), "00ff").unwrap();
 
         // Migrate
         let result = migrate_storage(&old_dir.path().to_path_buf(), &new_dir.path().to_path_buf());
@@ -402,6 +824,10 @@ mod tests {
         let new_sessions = new_dir.path().join("sessions");
         assert!(new_sessions.join("2024-01-01.md").exists());
         assert!(new_sessions.join("2024-01-02.md").exists());
+        assert_eq!(
+            fs::read_to_string(new_dir.path().join(INTEGRITY_KEY_FILE)).unwrap(),
+            "00ff"
+        );
 
         // Old files should be gone
         assert!(!old_sessions.join("2024-01-01.md").exists());
@@ -417,4 +843,36 @@ mod tests {
         assert!(dir.exists());
         assert!(dir.ends_with("test-bmad"));
     }
+
+    #[test]
+    fn test_logical_date() {
+        let day_starts_at: NaiveTime = "04:00".parse().unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+        let at = |h, m| date.and_hms_opt(h, m, 0).unwrap();
+
+        assert_eq!(logical_date(at(1, 30), day_starts_at), date.pred_opt().unwrap());
+        assert_eq!(logical_date(at(4, 0), day_starts_at), date);
+        assert_eq!(logical_date(at(23, 59), day_starts_at), date);
+        assert_eq!(logical_date(at(0, 0), NaiveTime::MIN), date);
+
+        let settings: Settings = serde_json::from_str(r#"{ "dayStartsAt": "04:00" }"#).unwrap();
+        assert_eq!(settings.day_starts_at, day_starts_at);
+    }
+
+    #[test]
+    fn test_week_start_of() {
+        // 2026-10-14 is a Wednesday
+        let date = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+
+        assert_eq!(
+            WeekStart::Monday.week_start_of(date),
+            NaiveDate::from_ymd_opt(2026, 10, 12).unwrap()
+        );
+        assert_eq!(
+            WeekStart::Sunday.week_start_of(date),
+            NaiveDate::from_ymd_opt(2026, 10, 11).unwrap()
+        );
+        let sunday = NaiveDate::from_ymd_opt(2026, 10, 11).unwrap();
+        assert_eq!(WeekStart::Sunday.week_start_of(sunday), sunday);
+    }
 }
@@ -0,0 +1,747 @@
+//! Statistics aggregation module
+//!
+//! Aggregates session files over an arbitrary date range, grouped by day,
+//! ISO week, month or year. Day summaries used by the heatmap and achievements are
+//! cached in memory and only re-parsed when a session file's modification time or
+//! size changes, or for verified summaries when the integrity key changes.
+
+use chrono::{Datelike, Duration, Locale, NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::SystemTime;
+
+use crate::error::AppError;
+use crate::storage::integrity::{keep_verified_sessions, load_or_create_integrity_key};
+use crate::storage::settings::{load_settings, Goal, GoalMetric};
+use crate::storage::sessions::{
+    get_sessions_directory, load_sessions_in_range, DailySessionFile, DailySummary, DateRange,
+    Session, SessionStatus, SessionType,
+};
+
+/// Number of intensity levels above zero in the heatmap
+const HEATMAP_LEVELS: usize = 4;
+
+/// Period used to group statistics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupBy {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl GroupBy {
+    /// First day of the period containing `date` (weeks start on Monday, ISO 8601)
+    pub fn period_start(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            GroupBy::Day => date,
+            GroupBy::Week => date - Duration::days(date.weekday().num_days_from_monday() as i64),
+            GroupBy::Month => date.with_day(1).unwrap(),
+            GroupBy::Year => date.with_ordinal(1).unwrap(),
+        }
+    }
+
+    /// First day of the period following the one starting at `start`
+    pub fn next_period_start(&self, start: NaiveDate) -> NaiveDate {
+        match self {
+            GroupBy::Day => start + Duration::days(1),
+            GroupBy::Week => start + Duration::days(7),
+            GroupBy::Month => {
+                let (year, month) = if start.month() == 12 {
+                    (start.year() + 1, 1)
+                } else {
+                    (start.year(), start.month() + 1)
+                };
+                NaiveDate::from_ymd_opt(year, month, 1).unwrap()
+            }
+            GroupBy::Year => NaiveDate::from_ymd_opt(start.year() + 1, 1, 1).unwrap(),
+        }
+    }
+
+    /// Display key of the period starting at `start`, e.g. `2026-W02` or `2026-01`
+    pub fn period_key(&self, start: NaiveDate) -> String {
+        match self {
+            GroupBy::Day => start.format("%Y-%m-%d").to_string(),
+            GroupBy::Week => {
+                let week = start.iso_week();
+                format!("{}-W{:02}", week.year(), week.week())
+            }
+            GroupBy::Month => start.format("%Y-%m").to_string(),
+            GroupBy::Year => start.format("%Y").to_string(),
+        }
+    }
+}
+
+/// Key of the week starting on `start`, e.g. `2026-W41`: the ISO week holding most
+/// of its days, whichever day the week starts on
+pub fn week_key(start: NaiveDate) -> String {
+    GroupBy::Week.period_key(start + Duration::days(3))
+}
+
+/// Statistics for one period
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodStats {
+    pub key: String,
+    /// First day of the period covered by the query
+    pub start_date: String,
+    /// Last day of the period covered by the query
+    pub end_date: String,
+    pub focus_minutes: u32,
+    pub complete_count: u32,
+    pub interrupted_count: u32,
+    pub break_minutes: u32,
+    /// Average focus session length in minutes, 0 when there were no sessions
+    pub average_session_minutes: f64,
+    /// Whether the goal of the period was met (daily goal for days, weekly goal
+    /// for weeks). None for months, years, or when no goal is set.
+    pub goal_met: Option<bool>,
+}
+
+/// Response of the range statistics query
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RangeStats {
+    pub range: DateRange,
+    pub group_by: GroupBy,
+    pub groups: Vec<PeriodStats>,
+    pub totals: PeriodStats,
+    /// Goal to draw as a line, matching the grouping (day or week)
+    pub goal: Option<Goal>,
+}
+
+#[derive(Default)]
+struct Accumulator {
+    summary: DailySummary,
+    break_seconds: u32,
+    focus_seconds: u32,
+}
+
+impl Accumulator {
+    fn add_day(&mut self, sessions: &[Session]) {
+        self.summary.merge(&DailySummary::from_sessions(sessions));
+
+        for session in sessions {
+            match session.session_type {
+                SessionType::Focus => self.focus_seconds += session.duration_seconds,
+                SessionType::Break => self.break_seconds += session.duration_seconds,
+            }
+        }
+    }
+
+    fn into_stats(self, key: String, start: NaiveDate, end: NaiveDate) -> PeriodStats {
+        let session_count = self.summary.complete_count + self.summary.partial_count;
+        let average_session_minutes = if session_count > 0 {
+            self.focus_seconds as f64 / session_count as f64 / 60.0
+        } else {
+            0.0
+        };
+
+        PeriodStats {
+            key,
+            start_date: start.format("%Y-%m-%d").to_string(),
+            end_date: end.format("%Y-%m-%d").to_string(),
+            focus_minutes: self.summary.total_focus_minutes,
+            complete_count: self.summary.complete_count,
+            interrupted_count: self.summary.partial_count,
+            break_minutes: self.break_seconds / 60,
+            average_session_minutes,
+            goal_met: None,
+        }
+    }
+}
+
+/// Aggregate loaded days into periods. Every period overlapping the range is
+/// returned, including empty ones, clamped to the range boundaries.
+pub fn aggregate_stats(
+    range: &DateRange,
+    group_by: GroupBy,
+    days: &[(NaiveDate, Vec<Session>)],
+) -> RangeStats {
+    let mut groups = Vec::new();
+    let mut totals = Accumulator::default();
+    let mut days = days
+        .iter()
+        .filter(|(date, _)| range.contains(*date))
+        .peekable();
+
+    let mut period_start = group_by.period_start(range.start);
+    while period_start <= range.end {
+        let next_start = group_by.next_period_start(period_start);
+        let mut acc = Accumulator::default();
+
+        while let Some((date, sessions)) = days.next_if(|(date, _)| *date < next_start) {
+            debug_assert!(*date >= period_start);
+            acc.add_day(sessions);
+            totals.add_day(sessions);
+        }
+
+        let start = period_start.max(range.start);
+        let end = (next_start - Duration::days(1)).min(range.end);
+        groups.push(acc.into_stats(group_by.period_key(period_start), start, end));
+
+        period_start = next_start;
+    }
+
+    RangeStats {
+        range: *range,
+        group_by,
+        groups,
+        totals: totals.into_stats("total".to_string(), range.start, range.end),
+        goal: None,
+    }
+}
+
+/// Load and aggregate the sessions of a date range
+pub fn get_range_stats(range: &DateRange, group_by: GroupBy) -> Result<RangeStats, AppError> {
+    let days = load_sessions_in_range(range)?;
+    let mut stats = aggregate_stats(range, group_by, &days);
+
+    let goals = load_settings()?.goals;
+    stats.goal = match group_by {
+        GroupBy::Day => goals.daily,
+        GroupBy::Week => goals.weekly,
+        GroupBy::Month | GroupBy::Year => None,
+    };
+    if let Some(goal) = stats.goal {
+        for group in &mut stats.groups {
+            let value = match goal.metric {
+                GoalMetric::FocusMinutes => group.focus_minutes,
+                GoalMetric::CompletedSessions => group.complete_count,
+            };
+            group.goal_met = Some(value >= goal.target);
+        }
+    }
+
+    Ok(stats)
+}
+
+fn parse_locale(tag: &str) -> Option<Locale> {
+    // Accept "fr-FR", "fr_FR.UTF-8" and bare languages such as "fr"
+    let tag = tag.split(['.', '@']).next()?.replace('-', "_");
+    Locale::try_from(tag.as_str()).ok().or_else(|| {
+        if tag.contains('_') {
+            None
+        } else {
+            Locale::try_from(format!("{}_{}", tag, tag.to_uppercase()).as_str()).ok()
+        }
+    })
+}
+
+/// Locale for day names: the configured one, else the system locale, else en_US
+pub fn resolve_locale(configured: Option<&str>) -> Locale {
+    configured
+        .and_then(parse_locale)
+        .or_else(|| sys_locale::get_locale().as_deref().and_then(parse_locale))
+        .unwrap_or(Locale::en_US)
+}
+
+/// Abbreviated day name in the given locale, e.g. "Mon" or "lun."
+pub fn day_abbreviation(date: NaiveDate, locale: Locale) -> String {
+    date.format_localized("%a", locale).to_string()
+}
+
+/// What the aggregations need from one day file: its summary and the start times
+/// of its completed focus sessions
+#[derive(Debug, Clone, Default)]
+pub struct DayDigest {
+    pub summary: DailySummary,
+    pub complete_starts: Vec<NaiveTime>,
+    /// Number of sessions in the file, focus and breaks
+    pub session_count: u32,
+}
+
+impl DayDigest {
+    pub fn from_sessions(sessions: &[Session]) -> Self {
+        DayDigest {
+            summary: DailySummary::from_sessions(sessions),
+            complete_starts: sessions
+                .iter()
+                .filter(|s| {
+                    s.session_type == SessionType::Focus && s.status == SessionStatus::Complete
+                })
+                .map(|s| s.start_time.time())
+                .collect(),
+            session_count: sessions.len() as u32,
+        }
+    }
+}
+
+/// Digest of one session file, valid while the file's modification time and size are unchanged
+#[derive(Debug, Clone)]
+struct CachedDay {
+    modified: SystemTime,
+    len: u64,
+    digest: DayDigest,
+}
+
+type SummaryCache = HashMap<PathBuf, CachedDay>;
+
+fn summary_cache() -> &'static Mutex<SummaryCache> {
+    static CACHE: OnceLock<Mutex<SummaryCache>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Digests counting only verified sessions, valid for the integrity key they were
+/// verified with
+#[derive(Default)]
+struct VerifiedCache {
+    key: Vec<u8>,
+    days: SummaryCache,
+}
+
+impl VerifiedCache {
+    /// Cached days for `key`, dropped when the key changed (restored or migrated)
+    fn for_key(&mut self, key: &[u8]) -> &mut SummaryCache {
+        if self.key != key {
+            self.days.clear();
+            self.key = key.to_vec();
+        }
+        &mut self.days
+    }
+}
+
+/// Same as `summary_cache`, for summaries counting only verified sessions
+fn verified_summary_cache() -> &'static Mutex<VerifiedCache> {
+    static CACHE: OnceLock<Mutex<VerifiedCache>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(VerifiedCache::default()))
+}
+
+/// Digests of the session files of `dir` within `range`, re-parsing only the files
+/// that changed since they were cached. `summarize` turns a day's sessions into its
+/// digest. Days without a file are skipped.
+fn load_digests_in_dir(
+    dir: &Path,
+    range: &DateRange,
+    cache: &mut SummaryCache,
+    summarize: &dyn Fn(DailySessionFile) -> DayDigest,
+) -> Result<Vec<(NaiveDate, DayDigest)>, AppError> {
+    range.validate()?;
+
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let entries = fs::read_dir(dir)
+        .map_err(|e| AppError::StorageError(format!("Failed to read sessions directory: {}", e)))?;
+
+    let mut files: Vec<(NaiveDate, PathBuf)> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            let stem = path.file_name()?.to_str()?.strip_suffix(".md")?;
+            let date = NaiveDate::parse_from_str(stem, "%Y-%m-%d").ok()?;
+            range.contains(date).then_some((date, path))
+        })
+        .collect();
+    files.sort();
+
+    let mut digests = Vec::with_capacity(files.len());
+    for (date, path) in files {
+        let metadata = fs::metadata(&path).map_err(|e| {
+            AppError::StorageError(format!("Failed to read session file metadata: {}", e))
+        })?;
+        let modified = metadata.modified().map_err(|e| {
+            AppError::StorageError(format!("Failed to read session file metadata: {}", e))
+        })?;
+        let len = metadata.len();
+
+        let cached = cache
+            .get(&path)
+            .filter(|c| c.modified == modified && c.len == len)
+            .map(|c| c.digest.clone());
+        let digest = match cached {
+            Some(digest) => digest,
+            None => {
+                let content = fs::read_to_string(&path).map_err(|e| {
+                    AppError::StorageError(format!("Failed to read session file: {}", e))
+                })?;
+                let digest = summarize(DailySessionFile::from_markdown(&content, date)?);
+                cache.insert(
+                    path,
+                    CachedDay {
+                        modified,
+                        len,
+                        digest: digest.clone(),
+                    },
+                );
+                digest
+            }
+        };
+        digests.push((date, digest));
+    }
+
+    Ok(digests)
+}
+
+/// Day digests of the effective sessions directory within `range`, using the cache
+pub fn load_day_digests(range: &DateRange) -> Result<Vec<(NaiveDate, DayDigest)>, AppError> {
+    let dir = get_sessions_directory()?;
+    let mut cache = summary_cache()
+        .lock()
+        .map_err(|_| AppError::StorageError("Stats cache lock poisoned".to_string()))?;
+    load_digests_in_dir(&dir, range, &mut cache, &|daily_file| {
+        DayDigest::from_sessions(&daily_file.sessions)
+    })
+}
+
+/// Day digests counting only the sessions that pass verification, using the cache
+pub fn load_verified_day_digests(
+    range: &DateRange,
+) -> Result<Vec<(NaiveDate, DayDigest)>, AppError> {
+    let dir = get_sessions_directory()?;
+    let key = load_or_create_integrity_key()?;
+    let mut cache = verified_summary_cache()
+        .lock()
+        .map_err(|_| AppError::StorageError("Stats cache lock poisoned".to_string()))?;
+    load_digests_in_dir(&dir, range, cache.for_key(&key), &|daily_file| {
+        DayDigest::from_sessions(&keep_verified_sessions(&key, daily_file))
+    })
+}
+
+/// Day summaries of the effective sessions directory within `range`, using the cache
+pub fn load_daily_summaries(range: &DateRange) -> Result<Vec<(NaiveDate, DailySummary)>, AppError> {
+    Ok(load_day_digests(range)?
+        .into_iter()
+        .map(|(date, digest)| (date, digest.summary))
+        .collect())
+}
+
+/// Day summaries counting only the sessions that pass verification, using the cache
+pub fn load_verified_daily_summaries(
+    range: &DateRange,
+) -> Result<Vec<(NaiveDate, DailySummary)>, AppError> {
+    Ok(load_verified_day_digests(range)?
+        .into_iter()
+        .map(|(date, digest)| (date, digest.summary))
+        .collect())
+}
+
+/// One cell of the heatmap
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatmapDay {
+    pub date: String,
+    pub focus_minutes: u32,
+    pub session_count: u32,
+    /// Intensity from 0 (no focus) to 4 (top quartile of the user's active days)
+    pub level: u8,
+}
+
+/// Focus activity for every day of a year
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatmapResponse {
+    pub year: i32,
+    pub days: Vec<HeatmapDay>,
+    /// Upper bounds in minutes of levels 1 to 3; anything above the last one is level 4
+    pub thresholds: Vec<u32>,
+    pub total_focus_minutes: u32,
+    pub active_days: u32,
+}
+
+/// Level boundaries from the 25th, 50th and 75th percentiles (nearest rank) of active days
+pub fn heatmap_thresholds(focus_minutes: &[u32]) -> Vec<u32> {
+    let mut active: Vec<u32> = focus_minutes.iter().copied().filter(|m| *m > 0).collect();
+    if active.is_empty() {
+        return Vec::new();
+    }
+    active.sort_unstable();
+
+    (1..HEATMAP_LEVELS)
+        .map(|level| {
+            let rank = (level * active.len()).div_ceil(HEATMAP_LEVELS);
+            active[rank.max(1) - 1]
+        })
+        .collect()
+}
+
+fn heatmap_level(focus_minutes: u32, thresholds: &[u32]) -> u8 {
+    if focus_minutes == 0 {
+        return 0;
+    }
+    let below = thresholds
+        .iter()
+        .take_while(|t| focus_minutes > **t)
+        .count();
+    (below + 1) as u8
+}
+
+/// Build the heatmap of a year from its day summaries
+pub fn build_heatmap(year: i32, summaries: &[(NaiveDate, DailySummary)]) -> HeatmapResponse {
+    let by_date: HashMap<NaiveDate, &DailySummary> = summaries
+        .iter()
+        .map(|(date, summary)| (*date, summary))
+        .collect();
+    let minutes: Vec<u32> = summaries
+        .iter()
+        .map(|(_, s)| s.total_focus_minutes)
+        .collect();
+    let thresholds = heatmap_thresholds(&minutes);
+
+    let mut days = Vec::new();
+    let mut total_focus_minutes = 0;
+    let mut active_days = 0;
+    let mut date = NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
+    while date.year() == year {
+        let (focus_minutes, session_count) = by_date
+            .get(&date)
+            .map(|s| (s.total_focus_minutes, s.complete_count + s.partial_count))
+            .unwrap_or((0, 0));
+        total_focus_minutes += focus_minutes;
+        if focus_minutes > 0 {
+            active_days += 1;
+        }
+
+        days.push(HeatmapDay {
+            date: date.format("%Y-%m-%d").to_string(),
+            focus_minutes,
+            session_count,
+            level: heatmap_level(focus_minutes, &thresholds),
+        });
+        date += Duration::days(1);
+    }
+
+    HeatmapResponse {
+        year,
+        days,
+        thresholds,
+        total_focus_minutes,
+        active_days,
+    }
+}
+
+/// Heatmap data for a calendar year
+pub fn get_year_heatmap(year: i32) -> Result<HeatmapResponse, AppError> {
+    let range = DateRange {
+        start: NaiveDate::from_ymd_opt(year, 1, 1)
+            .ok_or_else(|| AppError::ParseError(format!("Invalid year: {}", year)))?,
+        end: NaiveDate::from_ymd_opt(year, 12, 31)
+            .ok_or_else(|| AppError::ParseError(format!("Invalid year: {}", year)))?,
+    };
+    let summaries = load_daily_summaries(&range)?;
+    Ok(build_heatmap(year, &summaries))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::sessions::test_session;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn session(
+        day: NaiveDate,
+        minutes: i64,
+        status: SessionStatus,
+        session_type: SessionType,
+    ) -> Session {
+        test_session(day, (9, 0), minutes, status, session_type)
+    }
+
+    #[test]
+    fn test_group_by_week_uses_iso_weeks() {
+        // 2026-01-01 is a Thursday, in ISO week 2026-W01
+        let range = DateRange {
+            start: date(2026, 1, 1),
+            end: date(2026, 1, 12),
+        };
+        let days = vec![
+            (
+                date(2026, 1, 2),
+                vec![session(
+                    date(2026, 1, 2),
+                    25,
+                    SessionStatus::Complete,
+                    SessionType::Focus,
+                )],
+            ),
+            (
+                date(2026, 1, 5),
+                vec![
+                    session(
+                        date(2026, 1, 5),
+                        25,
+                        SessionStatus::Complete,
+                        SessionType::Focus,
+                    ),
+                    session(
+                        date(2026, 1, 5),
+                        5,
+                        SessionStatus::Complete,
+                        SessionType::Break,
+                    ),
+                    session(
+                        date(2026, 1, 5),
+                        10,
+                        SessionStatus::Interrupted,
+                        SessionType::Focus,
+                    ),
+                ],
+            ),
+        ];
+
+        let stats = aggregate_stats(&range, GroupBy::Week, &days);
+
+        let keys: Vec<&str> = stats.groups.iter().map(|g| g.key.as_str()).collect();
+        assert_eq!(keys, vec!["2026-W01", "2026-W02", "2026-W03"]);
+        assert_eq!(stats.groups[0].start_date, "2026-01-01");
+        assert_eq!(stats.groups[0].focus_minutes, 25);
+        assert_eq!(stats.groups[1].complete_count, 1);
+        assert_eq!(stats.groups[1].interrupted_count, 1);
+        assert_eq!(stats.groups[1].break_minutes, 5);
+        assert_eq!(stats.groups[1].average_session_minutes, 17.5);
+        assert_eq!(stats.groups[2].end_date, "2026-01-12");
+        assert_eq!(stats.groups[2].focus_minutes, 0);
+        assert_eq!(stats.totals.focus_minutes, 60);
+    }
+
+    #[test]
+    fn test_group_by_month_and_year() {
+        let range = DateRange {
+            start: date(2025, 11, 15),
+            end: date(2026, 2, 3),
+        };
+        let days = vec![
+            (
+                date(2025, 12, 31),
+                vec![session(
+                    date(2025, 12, 31),
+                    25,
+                    SessionStatus::Complete,
+                    SessionType::Focus,
+                )],
+            ),
+            (
+                date(2026, 1, 1),
+                vec![session(
+                    date(2026, 1, 1),
+                    25,
+                    SessionStatus::Complete,
+                    SessionType::Focus,
+                )],
+            ),
+        ];
+
+        let months = aggregate_stats(&range, GroupBy::Month, &days);
+        let keys: Vec<&str> = months.groups.iter().map(|g| g.key.as_str()).collect();
+        assert_eq!(keys, vec!["2025-11", "2025-12", "2026-01", "2026-02"]);
+        assert_eq!(months.groups[1].complete_count, 1);
+        assert_eq!(months.groups[2].complete_count, 1);
+
+        let years = aggregate_stats(&range, GroupBy::Year, &days);
+        assert_eq!(years.groups.len(), 2);
+        assert_eq!(years.groups[0].start_date, "2025-11-15");
+        assert_eq!(years.groups[1].end_date, "2026-02-03");
+        assert_eq!(years.totals.complete_count, 2);
+    }
+
+    #[test]
+    fn test_heatmap_levels_follow_percentiles() {
+        let thresholds = heatmap_thresholds(&[0, 10, 20, 30, 40, 50, 60, 70, 80]);
+        assert_eq!(thresholds, vec![20, 40, 60]);
+
+        assert_eq!(heatmap_level(0, &thresholds), 0);
+        assert_eq!(heatmap_level(10, &thresholds), 1);
+        assert_eq!(heatmap_level(20, &thresholds), 1);
+        assert_eq!(heatmap_level(45, &thresholds), 3);
+        assert_eq!(heatmap_level(80, &thresholds), 4);
+
+        // A single active day still gets a visible level
+        assert_eq!(heatmap_level(25, &heatmap_thresholds(&[25])), 1);
+    }
+
+    #[test]
+    fn test_build_heatmap_covers_whole_year() {
+        let summary = DailySummary {
+            complete_count: 2,
+            complete_minutes: 50,
+            partial_count: 0,
+            partial_minutes: 0,
+            total_focus_minutes: 50,
+        };
+        let heatmap = build_heatmap(2024, &[(date(2024, 2, 29), summary)]);
+
+        assert_eq!(heatmap.days.len(), 366);
+        assert_eq!(heatmap.active_days, 1);
+        assert_eq!(heatmap.total_focus_minutes, 50);
+        let leap_day = &heatmap.days[59];
+        assert_eq!(leap_day.date, "2024-02-29");
+        assert_eq!(leap_day.session_count, 2);
+        assert_eq!(leap_day.level, 1);
+    }
+
+    #[test]
+    fn test_summary_cache_reparses_only_changed_files() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let range = DateRange {
+            start: date(2026, 1, 1),
+            end: date(2026, 12, 31),
+        };
+        fs::write(
+            dir.join("2026-01-10.md"),
+            "# 2026-01-10\n\n## Sessions\n- 09:00 - 09:25 ✓ Complete (25m)\n",
+        )
+        .unwrap();
+        fs::write(dir.join("notes.md"), "not a day file").unwrap();
+
+        let mut cache = SummaryCache::new();
+        let summarize = |file: DailySessionFile| DayDigest::from_sessions(&file.sessions);
+        let first = load_digests_in_dir(dir, &range, &mut cache, &summarize).unwrap();
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].1.summary.total_focus_minutes, 25);
+        assert_eq!(first[0].1.complete_starts.len(), 1);
+        assert_eq!(cache.len(), 1);
+
+        // A stale cache entry is ignored once the file changes
+        fs::write(
+            dir.join("2026-01-10.md"),
+            "# 2026-01-10\n\n## Sessions\n- 09:00 - 09:25 ✓ Complete (25m)\n\
+             - 10:00 - 10:25 ✓ Complete (25m)\n",
+        )
+        .unwrap();
+        let second = load_digests_in_dir(dir, &range, &mut cache, &summarize).unwrap();
+        assert_eq!(second[0].1.summary.complete_count, 2);
+        assert_eq!(second[0].1.summary.total_focus_minutes, 50);
+        assert_eq!(second[0].1.complete_starts.len(), 2);
+    }
+
+    #[test]
+    fn test_verified_cache_is_dropped_when_the_key_changes() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let range = DateRange {
+            start: date(2026, 1, 1),
+            end: date(2026, 12, 31),
+        };
+        fs::write(
+            dir.join("2026-01-10.md"),
+            "# 2026-01-10\n\n## Sessions\n- 09:00 - 09:25 ✓ Complete (25m)\n",
+        )
+        .unwrap();
+
+        let mut cache = VerifiedCache::default();
+        let summarize = |file: DailySessionFile| DayDigest::from_sessions(&file.sessions);
+        load_digests_in_dir(dir, &range, cache.for_key(b"old-key"), &summarize).unwrap();
+        assert_eq!(cache.for_key(b"old-key").len(), 1);
+
+        assert!(cache.for_key(b"new-key").is_empty());
+    }
+
+    #[test]
+    fn test_localized_day_names() {
+        // 2026-01-05 is a Monday
+        let monday = date(2026, 1, 5);
+
+        assert_eq!(day_abbreviation(monday, resolve_locale(Some("en_US"))), "Mon");
+        assert_eq!(day_abbreviation(monday, resolve_locale(Some("de-DE"))), "Mo");
+        assert_eq!(day_abbreviation(monday, resolve_locale(Some("fr"))), "lun.");
+        assert_eq!(parse_locale("not a locale"), None);
+    }
+}